@@ -1,45 +1,34 @@
-use std::{
-    error::Error,
-    path::Path,
-    path::PathBuf,
+use anyhow::Context;
+
+use crate::{
+    params::{Param, Params},
+    parse_lines, Answer, Day,
 };
 
-use aoc2020_common::read_lines;
+pub static RUN: Day = Day::with_params(
+    &[
+        // Number of entries that need to add up to 2020 in each part.
+        Param::new("part1_terms", 2),
+        Param::new("part2_terms", 3),
+    ],
+    part1,
+    part2,
+)
+.titled("Report Repair");
 
-fn main() {
-    if let Some((path, count)) = parse_args() {
-        match solve(&path, count) {
-            Ok(result) => {
-                println!("{}: {}", path.display(), result);
-            }
-            Err(err) => {
-                println!("{}: {}", path.display(), err);
-            }
-        }
-    } else {
-        eprintln!("Usage: aoc2020-day01 path/to/input <SIZE-OF-SUM>");
-        std::process::exit(1);
-    }
+pub fn part1(input: &[u8], params: &Params) -> anyhow::Result<Answer> {
+    solve(input, params.get("part1_terms")?).map(Answer::from)
 }
 
-fn parse_args() -> Option<(PathBuf, usize)> {
-    let mut args = std::env::args_os().skip(1);
-    let path = args.next()?.into();
-    let count = args.next()?.to_str()?.parse().ok()?;
-    if args.next().is_none() {
-        Some((path, count))
-    } else {
-        None
-    }
+pub fn part2(input: &[u8], params: &Params) -> anyhow::Result<Answer> {
+    solve(input, params.get("part2_terms")?).map(Answer::from)
 }
 
-fn solve(path: &Path, count: usize) -> Result<u32, Box<dyn Error>> {
-    let mut numbers: Vec<u32> = read_lines(path)?;
+fn solve(input: &[u8], count: usize) -> anyhow::Result<u32> {
+    let mut numbers: Vec<u32> = parse_lines(input)?;
 
     numbers.sort();
-    find_product(count, 2020, &numbers).ok_or(
-        std::io::Error::new(std::io::ErrorKind::InvalidData, "No numbers add up to 2020").into(),
-    )
+    find_product(count, 2020, &numbers).context("No numbers add up to 2020")
 }
 
 fn find_product(count: usize, sum: u32, sorted_nums: &[u32]) -> Option<u32> {
@@ -1,52 +1,25 @@
-use std::{
-    error::Error,
-    fmt::{Debug, Display},
-    path::Path,
-    path::PathBuf,
-    str::FromStr,
-};
-
-use aoc2020_common::read_lines;
-
-fn main() {
-    if let Some(path) = parse_args() {
-        match solve(&path) {
-            Ok(result) => {
-                println!("{}: {:?}", path.display(), result);
-            }
-            Err(err) => {
-                println!("{}: {}", path.display(), err);
-            }
-        }
-    } else {
-        eprintln!("Usage: aoc2020-day02 path/to/input");
-        std::process::exit(1);
-    }
-}
+use std::{error::Error, fmt::Display, str::FromStr};
 
-fn parse_args() -> Option<PathBuf> {
-    let mut args = std::env::args_os().skip(1);
-    let path = args.next()?.into();
-    if args.next().is_none() {
-        Some(path)
-    } else {
-        None
-    }
-}
+use crate::{parse_lines, Answer, Day};
 
-/// Solve both parts of the riddle.
-/// The nth component of the tuple is the solution to the nth part of the riddle.
-fn solve(path: &Path) -> Result<(usize, usize), Box<dyn Error>> {
-    let password_entries: Vec<Entry> = read_lines(path)?;
+pub static RUN: Day = Day::new(part1, part2).titled("Password Philosophy");
+
+pub fn part1(input: &[u8]) -> anyhow::Result<Answer> {
+    let password_entries: Vec<Entry> = parse_lines(input)?;
     let range_valid = password_entries
         .iter()
         .filter(|e| e.is_valid_range())
         .count();
+    Ok(range_valid.into())
+}
+
+pub fn part2(input: &[u8]) -> anyhow::Result<Answer> {
+    let password_entries: Vec<Entry> = parse_lines(input)?;
     let exact_valid = password_entries
         .iter()
         .filter(|e| e.is_valid_exact())
         .count();
-    Ok((range_valid, exact_valid))
+    Ok(exact_valid.into())
 }
 
 #[derive(Debug)]
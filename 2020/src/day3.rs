@@ -1,48 +1,38 @@
-use std::{
-    error::Error,
-    fmt::{Debug, Display},
-    path::Path,
-    path::PathBuf,
-    str::FromStr,
+use std::{error::Error, fmt::Display, str::FromStr};
+
+use crate::{
+    params::{Param, Params},
+    parse_lines, Answer, Day,
 };
 
-use aoc2020_common::read_lines;
+pub static RUN: Day = Day::with_params(
+    &[
+        // The slope (right, down) whose trees are counted in part 1.
+        Param::new("part1_right", 3),
+        Param::new("part1_down", 1),
+    ],
+    part1,
+    part2,
+)
+.titled("Toboggan Trajectory");
 
-fn main() {
-    if let Some((path, slopes)) = parse_args() {
-        match solve(&path, &slopes) {
-            Ok(result) => {
-                println!("{}: {:?}", path.display(), result);
-            }
-            Err(err) => {
-                println!("{}: {}", path.display(), err);
-            }
-        }
-    } else {
-        eprintln!("Usage: aoc2020-day03 path/to/input [<x1> <y1> [<x2> <y2> [ ... ]]]");
-        std::process::exit(1);
-    }
+/// The slopes (right, down) whose tree counts are multiplied in part 2. Parameters are single
+/// integers, so this list stays a constant.
+const PART2_SLOPES: &[(usize, usize)] = &[(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)];
+
+pub fn part1(input: &[u8], params: &Params) -> anyhow::Result<Answer> {
+    let slope = (params.get("part1_right")?, params.get("part1_down")?);
+    anyhow::ensure!(slope.1 > 0, "the slope has to go down");
+    solve(input, &[slope]).map(Answer::from)
 }
 
-fn parse_args() -> Option<(PathBuf, Vec<(usize, usize)>)> {
-    let mut args = std::env::args_os().skip(1);
-    let path = args.next()?.into();
-    let mut slopes = Vec::new();
-    while let Some(slope_x) = args.next() {
-        let slope_x = slope_x.to_str()?.parse::<usize>().ok()?;
-        let slope_y = args.next()?.to_str()?.parse::<usize>().ok()?;
-        slopes.push((slope_x, slope_y));
-    }
-    if args.next().is_none() {
-        Some((path, slopes))
-    } else {
-        None
-    }
+pub fn part2(input: &[u8], _params: &Params) -> anyhow::Result<Answer> {
+    solve(input, PART2_SLOPES).map(Answer::from)
 }
 
 /// Solve the riddle. Part one is a special case of part two where exactly one slope is provided.
-fn solve(path: &Path, slopes: &[(usize, usize)]) -> Result<usize, Box<dyn Error>> {
-    let rows: Vec<Row> = read_lines(path)?;
+fn solve(input: &[u8], slopes: &[(usize, usize)]) -> anyhow::Result<usize> {
+    let rows: Vec<Row> = parse_lines(input)?;
 
     let mut tree_count_product = 1;
 
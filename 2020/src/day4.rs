@@ -1,34 +1,17 @@
-use std::{error::Error, path::Path, path::PathBuf};
+use crate::{Answer, Day};
 
-fn main() {
-    if let Some((path, validate_values)) = parse_args() {
-        match solve(&path, validate_values) {
-            Ok(result) => {
-                println!("{}: {:?}", path.display(), result);
-            }
-            Err(err) => {
-                println!("{}: {}", path.display(), err);
-            }
-        }
-    } else {
-        eprintln!("Usage: aoc2020-day04 path/to/input [--validate-values]");
-        std::process::exit(1);
-    }
+pub static RUN: Day = Day::new(part1, part2).titled("Passport Processing");
+
+pub fn part1(input: &[u8]) -> anyhow::Result<Answer> {
+    solve(input, false).map(Answer::from)
 }
 
-fn parse_args() -> Option<(PathBuf, bool)> {
-    let mut args = std::env::args_os().skip(1);
-    let path = args.next()?.into();
-    let validate_values = args.next() == Some("--validate-values".into());
-    if args.next().is_none() {
-        Some((path, validate_values))
-    } else {
-        None
-    }
+pub fn part2(input: &[u8]) -> anyhow::Result<Answer> {
+    solve(input, true).map(Answer::from)
 }
 
-fn solve(path: &Path, validate_values: bool) -> Result<usize, Box<dyn Error>> {
-    let batch_data = std::fs::read_to_string(path)?;
+fn solve(input: &[u8], validate_values: bool) -> anyhow::Result<usize> {
+    let batch_data = std::str::from_utf8(input)?;
     let valid_count = batch_data
         .split("\n\n")
         .filter(|pp| validate_passport(pp, validate_values))
@@ -85,14 +68,13 @@ fn is_passport_id(input: &str) -> bool {
 }
 
 fn is_height_valid(input: &str) -> bool {
-    if input.ends_with("cm") {
-        input[..input.len() - 2]
-            .parse::<u32>()
-            .map_or(false, |num| num >= 150 && num <= 193)
-    } else if input.ends_with("in") {
-        input[..input.len() - 2]
+    if let Some(cm) = input.strip_suffix("cm") {
+        cm.parse::<u32>()
+            .map_or(false, |num| (150..=193).contains(&num))
+    } else if let Some(inches) = input.strip_suffix("in") {
+        inches
             .parse::<u32>()
-            .map_or(false, |num| num >= 59 && num <= 76)
+            .map_or(false, |num| (59..=76).contains(&num))
     } else {
         false
     }
@@ -0,0 +1,60 @@
+use anyhow::Context;
+
+use crate::{Answer, Day};
+
+pub static RUN: Day = Day::new(part1, part2).titled("Binary Boarding");
+
+pub fn part1(input: &[u8]) -> anyhow::Result<Answer> {
+    let seat_ids = sorted_seat_ids(input);
+    // Sanity check: highest seat ID
+    let max_id = seat_ids
+        .last()
+        .context("must have at least one seat number for determining maximum")?;
+    Ok((*max_id).into())
+}
+
+pub fn part2(input: &[u8]) -> anyhow::Result<Answer> {
+    let seat_ids = sorted_seat_ids(input);
+    // Find discontinuity
+    let my_seat = seat_ids
+        .iter()
+        .zip(seat_ids.iter().skip(1))
+        .find_map(|(cur, next)| {
+            if cur + 2 == *next {
+                Some(cur + 1)
+            } else {
+                None
+            }
+        })
+        .context("no free seat found")?;
+    Ok(my_seat.into())
+}
+
+fn sorted_seat_ids(input: &[u8]) -> Vec<u32> {
+    // The seat numbers are just binary-encoded numbers using F/L as 0 and B/R as 1.
+    let mut seat_ids: Vec<u32> = input
+        .split(|ch| *ch == b'\n')
+        .filter(|line| !line.is_empty())
+        .map(parse_seat_id)
+        .collect();
+    seat_ids.sort();
+    seat_ids
+}
+
+fn parse_seat_id(line: &[u8]) -> u32 {
+    let mut id = 0;
+    for x in line.iter() {
+        id <<= 1;
+        if *x == b'B' || *x == b'R' {
+            id += 1;
+        }
+    }
+    id
+}
+
+#[test]
+fn test_seat_number_from_str() {
+    assert_eq!(parse_seat_id(b"BFFFBBFRRR"), 567);
+    assert_eq!(parse_seat_id(b"FFFBBBFRRR"), 119);
+    assert_eq!(parse_seat_id(b"BBFFBBFRLL"), 820);
+}
@@ -0,0 +1,37 @@
+use std::collections::HashSet;
+
+use crate::{Answer, Day};
+
+pub static RUN: Day = Day::new(part1, part2).titled("Custom Customs");
+
+pub fn part1(input: &[u8]) -> anyhow::Result<Answer> {
+    let data = std::str::from_utf8(input)?;
+    let any_count: usize = data.split("\n\n").map(count_any_yes).sum();
+    Ok(any_count.into())
+}
+
+pub fn part2(input: &[u8]) -> anyhow::Result<Answer> {
+    let data = std::str::from_utf8(input)?;
+    let all_count: usize = data.split("\n\n").map(count_all_yes).sum();
+    Ok(all_count.into())
+}
+
+fn count_any_yes(group: &str) -> usize {
+    gather_answers(group).len()
+}
+
+fn gather_answers(yesses: &str) -> HashSet<char> {
+    yesses
+        .chars()
+        .filter(|ch| ch.is_ascii_alphabetic())
+        .collect::<HashSet<_>>()
+}
+
+fn count_all_yes(group: &str) -> usize {
+    let mut answers_per_person = group.lines().map(gather_answers);
+    let mut acc = answers_per_person.next().unwrap_or(HashSet::new());
+    for other in answers_per_person {
+        acc.retain(|ch| other.contains(ch));
+    }
+    acc.len()
+}
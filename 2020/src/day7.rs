@@ -1,71 +1,44 @@
-use std::{
-    collections::HashMap, collections::HashSet, error::Error, fmt::Display, path::Path,
-    path::PathBuf, str::FromStr,
-};
-
-use aoc2020_common::read_lines;
-
-fn main() {
-    if let Some(path) = parse_args() {
-        match solve(&path) {
-            Ok(result) => {
-                println!("{}: {:?}", path.display(), result);
-            }
-            Err(err) => {
-                println!("{}: {}", path.display(), err);
-            }
-        }
-    } else {
-        eprintln!("Usage: aoc2020-day04 path/to/input [--validate-values]");
-        std::process::exit(1);
-    }
-}
+use std::{collections::HashMap, collections::HashSet, error::Error, fmt::Display, str::FromStr};
 
-fn parse_args() -> Option<PathBuf> {
-    let mut args = std::env::args_os().skip(1);
-    let path = args.next()?.into();
-    if args.next().is_none() {
-        Some(path)
-    } else {
-        None
-    }
-}
+use crate::{parse_lines, Answer, Day};
 
-fn solve(path: &Path) -> Result<(usize, usize), Box<dyn Error>> {
-    let rules: Vec<Rule> = read_lines(path)?;
-
-    // build graph where each bag points to those bags that can directly contain it
-    let mut outer_map: HashMap<&str, Vec<&str>> = HashMap::new();
-    // and another one where each bag points to the rule describing which bags it can contain
-    let mut inner_map: HashMap<&str, &Rule> = HashMap::new();
-    for rule in rules.iter() {
-        for (_, contained) in rule.contains.iter() {
-            let outer = outer_map.entry(contained).or_default();
-            outer.push(&rule.color);
-        }
-        inner_map.insert(rule.color.as_ref(), rule);
-    }
+pub static RUN: Day = Day::new(part1, part2).titled("Handy Haversacks");
+
+pub fn part1(input: &[u8]) -> anyhow::Result<Answer> {
+    let rules: Vec<Rule> = parse_lines(input)?;
+    let graph = BagGraph::new(&rules);
 
     // count number of nodes that eventually contain `shiny gold`.
     let mut visited: HashSet<&str> = HashSet::new();
 
-    let mut work_stack = outer_map.get("shiny gold").cloned().unwrap_or_else(|| Vec::new());
+    let mut work_stack = graph
+        .outer_map
+        .get("shiny gold")
+        .cloned()
+        .unwrap_or_else(Vec::new);
 
     while let Some(node) = work_stack.pop() {
         if visited.insert(node) {
-            if let Some(outer) = outer_map.get(node) {
+            if let Some(outer) = graph.outer_map.get(node) {
                 work_stack.extend(outer.iter())
             }
         }
     }
 
+    Ok(visited.len().into())
+}
+
+pub fn part2(input: &[u8]) -> anyhow::Result<Answer> {
+    let rules: Vec<Rule> = parse_lines(input)?;
+    let graph = BagGraph::new(&rules);
+
     // count number of bags inside a `shiny gold` bag
     let mut total_count: usize = 0;
     // The bag colors that still need to be processed, and how many we need of those
     let mut bag_stack = vec![(1, "shiny gold")];
 
     while let Some((multiplier, bag)) = bag_stack.pop() {
-        if let Some(rule) = inner_map.get(bag) {
+        if let Some(rule) = graph.inner_map.get(bag) {
             for (count, color) in rule.contains.iter() {
                 let this_count = multiplier * count;
                 total_count += this_count;
@@ -74,8 +47,32 @@ fn solve(path: &Path) -> Result<(usize, usize), Box<dyn Error>> {
         }
     }
 
+    Ok(total_count.into())
+}
+
+struct BagGraph<'a> {
+    /// Each bag points to those bags that can directly contain it
+    outer_map: HashMap<&'a str, Vec<&'a str>>,
+    /// Each bag points to the rule describing which bags it can contain
+    inner_map: HashMap<&'a str, &'a Rule>,
+}
 
-    Ok((visited.len(), total_count))
+impl<'a> BagGraph<'a> {
+    fn new(rules: &'a [Rule]) -> Self {
+        let mut outer_map: HashMap<&str, Vec<&str>> = HashMap::new();
+        let mut inner_map: HashMap<&str, &Rule> = HashMap::new();
+        for rule in rules.iter() {
+            for (_, contained) in rule.contains.iter() {
+                let outer = outer_map.entry(contained).or_default();
+                outer.push(&rule.color);
+            }
+            inner_map.insert(rule.color.as_ref(), rule);
+        }
+        Self {
+            outer_map,
+            inner_map,
+        }
+    }
 }
 
 #[derive(Debug)]
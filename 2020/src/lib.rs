@@ -0,0 +1,47 @@
+use std::str::FromStr;
+
+use anyhow::Context;
+
+aoc_core::days! {
+    day1,
+    day2,
+    day3,
+    day4,
+    day5,
+    day6,
+    day7,
+}
+
+pub use aoc_core::{
+    answer, aoc_main, cycle, grid, interval, params, parsers, runner, search, vector, Answer, Day,
+    Year,
+};
+
+pub static YEAR: Year = Year {
+    year: 2020,
+    days: DAYS,
+    inputs: concat!(env!("CARGO_MANIFEST_DIR"), "/inputs"),
+    src: concat!(env!("CARGO_MANIFEST_DIR"), "/src"),
+};
+
+/// Parse each line as one entry of the vector, according to the FromStr instance.
+pub fn parse_lines<T: FromStr>(input: &[u8]) -> anyhow::Result<Vec<T>>
+where
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    let input = std::str::from_utf8(input)?;
+
+    input
+        .lines()
+        .enumerate()
+        .map(|(line_number, line)| {
+            line.parse::<T>()
+                .with_context(|| format!("error in line {}", line_number + 1))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod input_tests {
+    include!(concat!(env!("OUT_DIR"), "/input_tests.rs"));
+}
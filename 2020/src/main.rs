@@ -0,0 +1,8 @@
+use advent_of_code_2020::{aoc_main, YEAR};
+
+#[global_allocator]
+static ALLOCATOR: aoc_core::memory::CountingAllocator = aoc_core::memory::CountingAllocator;
+
+fn main() -> anyhow::Result<()> {
+    aoc_main(&[YEAR])
+}
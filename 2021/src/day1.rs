@@ -4,20 +4,20 @@ use nom::{
     sequence::terminated,
 };
 
-use crate::{parsers, Day};
+use crate::{parsers, Answer, Day};
 use std::{cmp::Ordering, collections::VecDeque};
 
-pub static RUN: Day = Day { part1, part2 };
+pub static RUN: Day = Day::new(part1, part2).titled("Sonar Sweep");
 
-pub fn part1(input: &[u8]) -> anyhow::Result<String> {
+pub fn part1(input: &[u8]) -> anyhow::Result<Answer> {
     day1_impl(input, 1)
 }
 
-pub fn part2(input: &[u8]) -> anyhow::Result<String> {
+pub fn part2(input: &[u8]) -> anyhow::Result<Answer> {
     day1_impl(input, 3)
 }
 
-fn day1_impl(input: &[u8], window_size: usize) -> anyhow::Result<String> {
+fn day1_impl(input: &[u8], window_size: usize) -> anyhow::Result<Answer> {
     parsers::parse(
         flat_map(
             // first fill the scanning buffer
@@ -45,7 +45,7 @@ fn day1_impl(input: &[u8], window_size: usize) -> anyhow::Result<String> {
                             }
                         },
                     ),
-                    |count| format!("{}", count),
+                    Answer::from,
                 )
             },
         ),
@@ -74,5 +74,3 @@ impl Sonar {
         result
     }
 }
-
-crate::test_day!(crate::day1::RUN, "day1", "1527", "1575");
@@ -1,16 +1,16 @@
 #![allow(unused_imports)]
 
-use crate::{parsers, Day};
+use crate::{parsers, Answer, Day};
 use nom::bytes::complete::take_while;
 use nom::combinator::{flat_map, map};
 use nom::multi::fold_many0;
 use nom::sequence::terminated;
 use nom::IResult;
-pub static RUN: Day = Day { part1, part2 };
+pub static RUN: Day = Day::new(part1, part2).titled("Syntax Scoring");
 
 // TODO: share some more code between parts
 
-pub fn part1(input: &[u8]) -> anyhow::Result<String> {
+pub fn part1(input: &[u8]) -> anyhow::Result<Answer> {
     let mut stack = Vec::new();
     let mut total_score = 0;
     for line in input.split(|b| *b == b'\n') {
@@ -40,10 +40,10 @@ pub fn part1(input: &[u8]) -> anyhow::Result<String> {
         }
         // may or may not be incomplete
     }
-    Ok(format!("{}", total_score))
+    Ok(total_score.into())
 }
 
-pub fn part2(input: &[u8]) -> anyhow::Result<String> {
+pub fn part2(input: &[u8]) -> anyhow::Result<Answer> {
     let mut stack = Vec::new();
     let mut total_scores = Vec::new();
     for line in input.split(|b| *b == b'\n') {
@@ -81,7 +81,7 @@ pub fn part2(input: &[u8]) -> anyhow::Result<String> {
     }
     total_scores.sort_unstable();
     let final_score = total_scores[total_scores.len() / 2];
-    Ok(format!("{}", final_score))
+    Ok(final_score.into())
 }
 
 fn closing(opening: u8) -> u8 {
@@ -113,4 +113,3 @@ fn completion_score(closing: u8) -> i64 {
         _ => panic!("not a paren"),
     }
 }
-crate::test_day!(crate::day10::RUN, "day10", "315693", "1870887234");
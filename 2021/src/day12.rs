@@ -3,16 +3,16 @@
 use std::collections::{HashMap, HashSet};
 use std::rc::Rc;
 
-use crate::{parsers, Day};
+use crate::{parsers, Answer, Day};
 use anyhow::Context;
 use nom::bytes::complete::{tag, take_while};
 use nom::combinator::{flat_map, map, map_res};
 use nom::multi::fold_many0;
 use nom::sequence::{separated_pair, terminated};
 use nom::IResult;
-pub static RUN: Day = Day { part1, part2 };
+pub static RUN: Day = Day::new(part1, part2).titled("Passage Pathing");
 
-pub fn part1(input: &[u8]) -> anyhow::Result<String> {
+pub fn part1(input: &[u8]) -> anyhow::Result<Answer> {
     let graph = parsers::parse(p_graph, input)?;
 
     let mut num_paths = 0;
@@ -30,10 +30,10 @@ pub fn part1(input: &[u8]) -> anyhow::Result<String> {
         |_| num_paths += 1,
     );
 
-    Ok(format!("{}", num_paths))
+    Ok(num_paths.into())
 }
 
-pub fn part2(input: &[u8]) -> anyhow::Result<String> {
+pub fn part2(input: &[u8]) -> anyhow::Result<Answer> {
     let graph = parsers::parse(p_graph, input)?;
 
     let mut num_paths = 0;
@@ -51,7 +51,7 @@ pub fn part2(input: &[u8]) -> anyhow::Result<String> {
         |_| num_paths += 1,
     );
 
-    Ok(format!("{}", num_paths))
+    Ok(num_paths.into())
 }
 
 /// Brute force DFS solution for the problem. This might not be the most
@@ -176,10 +176,10 @@ impl Visitor for SmallOnceTwice {
 }
 
 fn is_large_cave(name: &str) -> bool {
-    !name.is_empty() && (b'A'..=b'Z').contains(&name.as_bytes()[0])
+    !name.is_empty() && name.as_bytes()[0].is_ascii_uppercase()
 }
 
-fn p_graph(input: &[u8]) -> IResult<&[u8], Graph> {
+fn p_graph(input: &[u8]) -> IResult<&[u8], Graph<'_>> {
     fold_many0(
         terminated(separated_pair(p_node, tag("-"), p_node), parsers::newline),
         Graph::default,
@@ -225,5 +225,3 @@ impl<'a> Graph<'a> {
         self.is_large[next as usize]
     }
 }
-
-crate::test_day!(crate::day12::RUN, "day12", "3802", "99448");
@@ -2,26 +2,26 @@
 
 use std::collections::HashSet;
 
-use crate::{parsers, Day};
+use crate::{answer::Image, parsers, Answer, Day};
 use nom::branch::alt;
 use nom::bytes::complete::{tag, take_while};
 use nom::combinator::{flat_map, map};
 use nom::multi::{fold_many0, many0};
 use nom::sequence::{preceded, separated_pair, terminated};
 use nom::IResult;
-pub static RUN: Day = Day { part1, part2 };
+pub static RUN: Day = Day::new(part1, part2).titled("Transparent Origami");
 
-pub fn part1(input: &[u8]) -> anyhow::Result<String> {
+pub fn part1(input: &[u8]) -> anyhow::Result<Answer> {
     let (mut points, folds) = parsers::parse(p_instructions, input)?;
 
     fold(folds[0], &mut points);
 
     let distinct = points.into_iter().collect::<HashSet<_>>();
 
-    Ok(format!("{}", distinct.len()))
+    Ok(distinct.len().into())
 }
 
-pub fn part2(input: &[u8]) -> anyhow::Result<String> {
+pub fn part2(input: &[u8]) -> anyhow::Result<Answer> {
     let (mut points, folds) = parsers::parse(p_instructions, input)?;
 
     for f in folds.iter() {
@@ -30,26 +30,11 @@ pub fn part2(input: &[u8]) -> anyhow::Result<String> {
         fold(*f, &mut points);
     }
 
-    Ok(render(&points))
+    Ok(render(&points).into())
 }
 
-fn render(points: &[Point]) -> String {
-    let lookup = points.iter().copied().collect::<HashSet<_>>();
-    let (max_x, max_y) = points
-        .iter()
-        .fold((0, 0), |(mx, my), p| (mx.max(p.x), my.max(p.y)));
-    let mut result = String::new();
-    for y in 0..=max_y {
-        for x in 0..=max_x {
-            if lookup.contains(&Point { x, y }) {
-                result.push('#');
-            } else {
-                result.push('.');
-            }
-        }
-        result.push('\n');
-    }
-    result
+fn render(points: &[Point]) -> Image {
+    Image::from_points(points.iter().map(|p| (p.x as usize, p.y as usize)))
 }
 
 fn fold(fold: Fold, points: &mut [Point]) {
@@ -109,16 +94,3 @@ struct Point {
     x: u32,
     y: u32,
 }
-
-crate::test_day!(
-    crate::day13::RUN,
-    "day13",
-    "708",
-    r"####.###..#....#..#.###..###..####.#..#
-#....#..#.#....#..#.#..#.#..#.#....#..#
-###..###..#....#..#.###..#..#.###..####
-#....#..#.#....#..#.#..#.###..#....#..#
-#....#..#.#....#..#.#..#.#.#..#....#..#
-####.###..####..##..###..#..#.#....#..#
-"
-);
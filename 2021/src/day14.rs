@@ -2,15 +2,15 @@
 
 use std::collections::HashMap;
 
-use crate::{parsers, Day};
+use crate::{parsers, Answer, Day};
 use nom::bytes::complete::{tag, take, take_while};
 use nom::combinator::{flat_map, map, map_opt};
 use nom::multi::{fold_many0, many0};
 use nom::sequence::{separated_pair, terminated};
 use nom::IResult;
-pub static RUN: Day = Day { part1, part2 };
+pub static RUN: Day = Day::new(part1, part2).titled("Extended Polymerization");
 
-pub fn part1(input: &[u8]) -> anyhow::Result<String> {
+pub fn part1(input: &[u8]) -> anyhow::Result<Answer> {
     let (template, rules) = parsers::parse(p_instructions, input)?;
 
     fn pair_index(pair: [u8; 2]) -> usize {
@@ -50,7 +50,7 @@ pub fn part1(input: &[u8]) -> anyhow::Result<String> {
         next.iter_mut().for_each(|c| *c = 0);
     }
 
-    let mut counts = vec![0; 26];
+    let mut counts = [0; 26];
     // In the pair representation, every character is counted twice, except for
     // the first and last. Fortunately, the first and last character never
     // change, hence we can just easily count them extra here.
@@ -77,10 +77,10 @@ pub fn part1(input: &[u8]) -> anyhow::Result<String> {
         }
     });
 
-    Ok(format!("{}", largest / 2 - smallest / 2))
+    Ok((largest / 2 - smallest / 2).into())
 }
 
-pub fn part2(input: &[u8]) -> anyhow::Result<String> {
+pub fn part2(input: &[u8]) -> anyhow::Result<Answer> {
     let (template, rules) = parsers::parse(p_instructions, input)?;
 
     // TODO: extract the parts common with part1
@@ -146,7 +146,7 @@ pub fn part2(input: &[u8]) -> anyhow::Result<String> {
         }
     });
 
-    Ok(format!("{}", largest / 2 - smallest / 2))
+    Ok((largest / 2 - smallest / 2).into())
 }
 
 fn p_instructions(input: &[u8]) -> IResult<&[u8], (&[u8], Vec<Rule>)> {
@@ -154,7 +154,7 @@ fn p_instructions(input: &[u8]) -> IResult<&[u8], (&[u8], Vec<Rule>)> {
 }
 
 fn p_template(input: &[u8]) -> IResult<&[u8], &[u8]> {
-    terminated(take_while(|b| matches!(b, b'A'..=b'Z')), parsers::newline)(input)
+    terminated(take_while(|b: u8| b.is_ascii_uppercase()), parsers::newline)(input)
 }
 
 fn p_rule(input: &[u8]) -> IResult<&[u8], Rule> {
@@ -162,9 +162,9 @@ fn p_rule(input: &[u8]) -> IResult<&[u8], Rule> {
     map_opt(
         separated_pair(take(2usize), tag(" -> "), take(1usize)),
         |(input, output): (&[u8], &[u8])| {
-            if matches!(input[0], b'A'..=b'Z')
-                && matches!(input[1], b'A'..=b'Z')
-                && matches!(output[0], b'A'..=b'Z')
+            if input[0].is_ascii_uppercase()
+                && input[1].is_ascii_uppercase()
+                && output[0].is_ascii_uppercase()
             {
                 Some(Rule {
                     input: [input[0], input[1]],
@@ -185,5 +185,3 @@ struct Rule {
     input: [u8; 2],
     output: u8,
 }
-
-crate::test_day!(crate::day14::RUN, "day14", "2851", "10002813279337");
@@ -1,18 +1,18 @@
 #![allow(unused_imports)]
 
-use crate::{parsers, Day};
+use crate::{parsers, Answer, Day};
 use nom::branch::alt;
 use nom::bytes::complete::{tag, take, take_while};
 use nom::combinator::{all_consuming, flat_map, map, map_res};
 use nom::multi::{fold_many0, fold_many1, many0, many_m_n};
 use nom::sequence::{pair, preceded, terminated, tuple};
 use nom::IResult;
-pub static RUN: Day = Day { part1, part2 };
+pub static RUN: Day = Day::new(part1, part2).titled("Packet Decoder");
 
-pub fn part1(input: &[u8]) -> anyhow::Result<String> {
+pub fn part1(input: &[u8]) -> anyhow::Result<Answer> {
     let bin_input = hex_to_bin(input);
     let pack = parsers::parse(terminated(p_packet, many0(tag("0"))), &bin_input)?;
-    Ok(version_sum(&pack).to_string())
+    Ok(version_sum(&pack).into())
 }
 
 fn version_sum(packet: &Packet) -> u32 {
@@ -23,10 +23,10 @@ fn version_sum(packet: &Packet) -> u32 {
         }
 }
 
-pub fn part2(input: &[u8]) -> anyhow::Result<String> {
+pub fn part2(input: &[u8]) -> anyhow::Result<Answer> {
     let bin_input = hex_to_bin(input);
     let pack = parsers::parse(terminated(p_packet, many0(tag("0"))), &bin_input)?;
-    Ok(eval(&pack).to_string())
+    Ok(eval(&pack).into())
 }
 
 fn eval(packet: &Packet) -> u64 {
@@ -183,7 +183,7 @@ fn test_parse_literal() {
 fn test_part2_examples() {
     fn check(input: &[u8], result: u64) {
         match part2(input) {
-            Ok(ret) => assert_eq!(ret, result.to_string()),
+            Ok(ret) => assert_eq!(ret, Answer::from(result)),
             Err(_) => panic!("could not parse"),
         }
     }
@@ -196,5 +196,3 @@ fn test_part2_examples() {
     check(b"9C005AC2F8F0", 0);
     check(b"9C0141080250320F1802104A08", 1);
 }
-
-crate::test_day!(crate::day16::RUN, "day16", "1014", "1922490999789");
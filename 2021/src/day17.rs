@@ -2,16 +2,16 @@
 
 use std::ops::RangeInclusive;
 
-use crate::{parsers, Day};
+use crate::{parsers, Answer, Day};
 use nom::bytes::complete::{tag, take_while};
 use nom::character::complete as numbers;
 use nom::combinator::{flat_map, map, opt};
 use nom::multi::fold_many0;
 use nom::sequence::{pair, preceded, separated_pair, terminated};
 use nom::IResult;
-pub static RUN: Day = Day { part1, part2 };
+pub static RUN: Day = Day::new(part1, part2).titled("Trick Shot");
 
-pub fn part1(input: &[u8]) -> anyhow::Result<String> {
+pub fn part1(input: &[u8]) -> anyhow::Result<Answer> {
     let target = parsers::parse(terminated(p_target, opt(parsers::newline)), input)?;
 
     if *target.y.end() >= 0 {
@@ -52,10 +52,10 @@ pub fn part1(input: &[u8]) -> anyhow::Result<String> {
         }
     }
 
-    Ok(highest.to_string())
+    Ok(highest.into())
 }
 
-pub fn part2(input: &[u8]) -> anyhow::Result<String> {
+pub fn part2(input: &[u8]) -> anyhow::Result<Answer> {
     let target = parsers::parse(terminated(p_target, opt(parsers::newline)), input)?;
 
     if *target.y.end() >= 0 {
@@ -95,7 +95,7 @@ pub fn part2(input: &[u8]) -> anyhow::Result<String> {
         }
     }
 
-    Ok(count.to_string())
+    Ok(count.into())
 }
 
 fn predict_x(start_x: i32, vel_x: i32, steps: u32) -> i32 {
@@ -225,5 +225,3 @@ struct Target {
     x: RangeInclusive<i32>,
     y: RangeInclusive<i32>,
 }
-
-crate::test_day!(crate::day17::RUN, "day17", "7750", "4120");
@@ -2,7 +2,7 @@
 
 use std::fmt::Display;
 
-use crate::{parsers, Day};
+use crate::{parsers, Answer, Day};
 use nom::branch::alt;
 use nom::bytes::complete::{tag, take_while};
 use nom::character::complete as numbers;
@@ -10,19 +10,19 @@ use nom::combinator::{flat_map, map};
 use nom::multi::{fold_many0, many0};
 use nom::sequence::{delimited, preceded, separated_pair, terminated};
 use nom::IResult;
-pub static RUN: Day = Day { part1, part2 };
+pub static RUN: Day = Day::new(part1, part2).titled("Snailfish");
 
-pub fn part1(input: &[u8]) -> anyhow::Result<String> {
+pub fn part1(input: &[u8]) -> anyhow::Result<Answer> {
     let nums = parsers::parse(many0(terminated(p_num, parsers::newline)), input)?;
     let mut result = nums[0].clone();
     for n in &nums[1..] {
         result = add(result, n.clone());
         result.reduce();
     }
-    Ok(result.magnitude().to_string())
+    Ok(result.magnitude().into())
 }
 
-pub fn part2(input: &[u8]) -> anyhow::Result<String> {
+pub fn part2(input: &[u8]) -> anyhow::Result<Answer> {
     let nums = parsers::parse(many0(terminated(p_num, parsers::newline)), input)?;
 
     let result = nums
@@ -36,7 +36,7 @@ pub fn part2(input: &[u8]) -> anyhow::Result<String> {
         .max()
         .unwrap();
 
-    Ok(result.to_string())
+    Ok(result.into())
 }
 
 fn add(a: Num, b: Num) -> Num {
@@ -154,5 +154,3 @@ impl Display for Num {
         }
     }
 }
-
-crate::test_day!(crate::day18::RUN, "day18", "4289", "4807");
@@ -1,9 +1,12 @@
 #![allow(unused_imports)]
 
 use std::collections::HashSet;
-use std::ops::{Add, Mul, Sub};
 
-use crate::{parsers, Day};
+use crate::{
+    parsers,
+    vector::{Rotation, ROTATIONS},
+    Answer, Day,
+};
 use anyhow::Context;
 use nom::bytes::complete::{tag, take_while};
 use nom::character::complete as numbers;
@@ -12,16 +15,16 @@ use nom::multi::{fold_many0, many0, separated_list0};
 use nom::sequence::{delimited, pair, terminated, tuple};
 use nom::IResult;
 use rustc_hash::FxHashSet;
-pub static RUN: Day = Day { part1, part2 };
+pub static RUN: Day = Day::new(part1, part2).titled("Beacon Scanner");
 
-pub fn part1(input: &[u8]) -> anyhow::Result<String> {
+pub fn part1(input: &[u8]) -> anyhow::Result<Answer> {
     let scanners = parsers::parse(p_input, input)?;
 
     let (absolute_points, _) = match_point_clouds(scanners)?;
-    Ok(absolute_points.len().to_string())
+    Ok(absolute_points.len().into())
 }
 
-pub fn part2(input: &[u8]) -> anyhow::Result<String> {
+pub fn part2(input: &[u8]) -> anyhow::Result<Answer> {
     let scanners = parsers::parse(p_input, input)?;
 
     let (_, scanner_positions) = match_point_clouds(scanners)?;
@@ -29,14 +32,13 @@ pub fn part2(input: &[u8]) -> anyhow::Result<String> {
     let mut largest = 0;
     for i in 0..scanner_positions.len() - 1 {
         for j in i..scanner_positions.len() {
-            let delta = scanner_positions[i] - scanner_positions[j];
-            let manhattan = delta.vs.iter().map(|v| v.abs()).sum();
+            let manhattan = scanner_positions[i].manhattan(scanner_positions[j]);
             if manhattan > largest {
                 largest = manhattan;
             }
         }
     }
-    Ok(largest.to_string())
+    Ok(largest.into())
 }
 
 fn match_point_clouds(mut scanners: Vec<Scanner>) -> Result<(Vec<Vec3>, Vec<Vec3>), anyhow::Error> {
@@ -62,7 +64,7 @@ fn match_point_clouds(mut scanners: Vec<Scanner>) -> Result<(Vec<Vec3>, Vec<Vec3
                 12,
             ) {
                 for p in scanner.points.iter() {
-                    let point = o.local_to_global(*p) + off;
+                    let point = o.apply(*p) + off;
                     if absolute_points.insert(point) {
                         reference_points.push(point);
                     }
@@ -82,21 +84,21 @@ fn match_scanner(
     reference_points: &[Vec3],
     scanner: &Scanner,
     threshold: u32,
-) -> Option<(Orientation, Vec3)> {
+) -> Option<(Rotation, Vec3)> {
     // check each orientation
-    for o in ORIENTATIONS {
+    for o in ROTATIONS {
         // take each of the original points as reference point
         for aref in reference_points {
             // assuming it corresponds to each point from the scanner
             for sref in scanner.points.iter() {
                 // Now check if the remaining points are consistent
-                let srot = o.local_to_global(*sref);
+                let srot = o.apply(*sref);
                 // Assumed offset
                 let offset = *aref - srot;
 
                 let mut matches = 0;
                 for spoint in scanner.points.iter() {
-                    let spoint_as_abs = o.local_to_global(*spoint) + offset;
+                    let spoint_as_abs = o.apply(*spoint) + offset;
                     matches += final_points.contains(&spoint_as_abs) as u32;
                 }
                 if matches >= threshold {
@@ -131,55 +133,7 @@ fn p_relpnt(input: &[u8]) -> IResult<&[u8], Vec3> {
     )(input)
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct Vec3 {
-    vs: [i32; 3],
-}
-
-impl Vec3 {
-    pub const fn new(x: i32, y: i32, z: i32) -> Self {
-        Self { vs: [x, y, z] }
-    }
-
-    pub const fn x(self) -> i32 {
-        self.vs[0]
-    }
-
-    pub const fn y(self) -> i32 {
-        self.vs[1]
-    }
-
-    pub const fn z(self) -> i32 {
-        self.vs[2]
-    }
-}
-
-impl Add for Vec3 {
-    type Output = Vec3;
-
-    #[inline]
-    fn add(self, rhs: Self) -> Self::Output {
-        Vec3::new(self.x() + rhs.x(), self.y() + rhs.y(), self.z() + rhs.z())
-    }
-}
-
-impl Sub for Vec3 {
-    type Output = Vec3;
-
-    #[inline]
-    fn sub(self, rhs: Self) -> Self::Output {
-        Vec3::new(self.x() - rhs.x(), self.y() - rhs.y(), self.z() - rhs.z())
-    }
-}
-
-impl Mul<i32> for Vec3 {
-    type Output = Vec3;
-
-    #[inline]
-    fn mul(self, rhs: i32) -> Self::Output {
-        Vec3::new(self.x() * rhs, self.y() * rhs, self.z() * rhs)
-    }
-}
+type Vec3 = crate::vector::Vec3<i32>;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 struct Scanner {
@@ -196,7 +150,7 @@ struct PointSet {
 impl PointSet {
     #[inline(always)]
     pub fn insert(&mut self, point: Vec3) -> bool {
-        let xyz = zigzag(point.vs.into_iter().sum());
+        let xyz = zigzag(point.x + point.y + point.z);
         let word = (xyz >> 6) as usize;
         let bit = xyz & 0b11_1111;
 
@@ -210,7 +164,7 @@ impl PointSet {
 
     #[inline(always)]
     pub fn contains(&self, point: &Vec3) -> bool {
-        let xyz = zigzag(point.vs.into_iter().sum());
+        let xyz = zigzag(point.x + point.y + point.z);
         let word = (xyz >> 6) as usize;
         let bit = xyz & 0b11_1111;
 
@@ -225,127 +179,6 @@ fn zigzag(n: i32) -> u32 {
     ((n << 1) ^ (n >> 31)) as u32
 }
 
-/// All 24 possible orientations of a scanner.
-const ORIENTATIONS: [Orientation; 24] = [
-    Orientation {
-        perm: [0, 1, 2],
-        sign: [1, 1, 1],
-    },
-    Orientation {
-        perm: [0, 2, 1],
-        sign: [1, 1, -1],
-    },
-    Orientation {
-        perm: [0, 1, 2],
-        sign: [1, -1, -1],
-    },
-    Orientation {
-        perm: [0, 2, 1],
-        sign: [1, -1, 1],
-    },
-    Orientation {
-        perm: [0, 1, 2],
-        sign: [-1, 1, -1],
-    },
-    Orientation {
-        perm: [0, 2, 1],
-        sign: [-1, 1, 1],
-    },
-    Orientation {
-        perm: [0, 1, 2],
-        sign: [-1, -1, 1],
-    },
-    Orientation {
-        perm: [0, 2, 1],
-        sign: [-1, -1, -1],
-    },
-    Orientation {
-        perm: [1, 0, 2],
-        sign: [1, 1, -1],
-    },
-    Orientation {
-        perm: [1, 2, 0],
-        sign: [1, 1, 1],
-    },
-    Orientation {
-        perm: [1, 0, 2],
-        sign: [1, -1, 1],
-    },
-    Orientation {
-        perm: [1, 2, 0],
-        sign: [1, -1, -1],
-    },
-    Orientation {
-        perm: [1, 0, 2],
-        sign: [-1, 1, 1],
-    },
-    Orientation {
-        perm: [1, 2, 0],
-        sign: [-1, 1, -1],
-    },
-    Orientation {
-        perm: [1, 0, 2],
-        sign: [-1, -1, -1],
-    },
-    Orientation {
-        perm: [1, 2, 0],
-        sign: [-1, -1, 1],
-    },
-    Orientation {
-        perm: [2, 0, 1],
-        sign: [1, 1, 1],
-    },
-    Orientation {
-        perm: [2, 1, 0],
-        sign: [1, 1, -1],
-    },
-    Orientation {
-        perm: [2, 0, 1],
-        sign: [1, -1, -1],
-    },
-    Orientation {
-        perm: [2, 1, 0],
-        sign: [1, -1, 1],
-    },
-    Orientation {
-        perm: [2, 0, 1],
-        sign: [-1, 1, -1],
-    },
-    Orientation {
-        perm: [2, 1, 0],
-        sign: [-1, 1, 1],
-    },
-    Orientation {
-        perm: [2, 0, 1],
-        sign: [-1, -1, 1],
-    },
-    Orientation {
-        perm: [2, 1, 0],
-        sign: [-1, -1, -1],
-    },
-];
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-struct Orientation {
-    /// Permutation of the vector components
-    perm: [u8; 3],
-    /// Rotation of the vector components
-    sign: [i8; 3],
-}
-
-impl Orientation {
-    #[inline(always)]
-    pub fn local_to_global(&self, v: Vec3) -> Vec3 {
-        Vec3 {
-            vs: [
-                self.sign[0] as i32 * v.vs[self.perm[0] as usize],
-                self.sign[1] as i32 * v.vs[self.perm[1] as usize],
-                self.sign[2] as i32 * v.vs[self.perm[2] as usize],
-            ],
-        }
-    }
-}
-
 #[test]
 fn test_p_relpnt() {
     assert_eq!(
@@ -401,5 +234,3 @@ fn test_p_input() {
         )
     );
 }
-
-crate::test_day!(crate::day19::RUN, "day19", "315", "13192");
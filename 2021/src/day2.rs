@@ -7,11 +7,11 @@ use nom::{
     IResult,
 };
 
-use crate::{parsers, Day};
+use crate::{parsers, Answer, Day};
 
-pub static RUN: Day = Day { part1, part2 };
+pub static RUN: Day = Day::new(part1, part2).titled("Dive!");
 
-pub fn part1(input: &[u8]) -> anyhow::Result<String> {
+pub fn part1(input: &[u8]) -> anyhow::Result<Answer> {
     parsers::parse(
         map(
             fold_many0(
@@ -23,13 +23,13 @@ pub fn part1(input: &[u8]) -> anyhow::Result<String> {
                     CtrlDir::Forward => (depth, x + cmd.amount),
                 },
             ),
-            |(depth, x)| format!("{}", depth * x),
+            |(depth, x)| Answer::from(depth * x),
         ),
         input,
     )
 }
 
-pub fn part2(input: &[u8]) -> anyhow::Result<String> {
+pub fn part2(input: &[u8]) -> anyhow::Result<Answer> {
     parsers::parse(
         map(
             fold_many0(
@@ -41,7 +41,7 @@ pub fn part2(input: &[u8]) -> anyhow::Result<String> {
                     CtrlDir::Forward => (aim, depth + aim * cmd.amount, x + cmd.amount),
                 },
             ),
-            |(_, depth, x)| format!("{}", depth * x),
+            |(_, depth, x)| Answer::from(depth * x),
         ),
         input,
     )
@@ -77,5 +77,3 @@ impl CtrlCmd {
         )(input)
     }
 }
-
-crate::test_day!(crate::day2::RUN, "day2", "1507611", "1880593125");
@@ -1,58 +1,43 @@
-#![allow(unused_imports)]
-
-use std::collections::HashMap;
-use std::ops::{Index, IndexMut};
-
-use crate::{parsers, Day};
+use crate::{
+    grid::{parse_grid, Grid},
+    parsers, Answer, Day,
+};
 use nom::bytes::complete::take_while;
-use nom::combinator::{flat_map, map, map_opt};
-use nom::multi::fold_many0;
+use nom::combinator::map_opt;
 use nom::sequence::{separated_pair, terminated};
 use nom::IResult;
-pub static RUN: Day = Day { part1, part2 };
+pub static RUN: Day = Day::new(part1, part2).titled("Trench Map");
 
 // TODO: super hacky
 
-pub fn part1(input: &[u8]) -> anyhow::Result<String> {
+pub fn part1(input: &[u8]) -> anyhow::Result<Answer> {
     let (algo, map) = parsers::parse(p_input, input)?;
-    let mut result = map.pad(10, 10, 10, 10, false);
+    let mut result = map.pad(10, false);
     for _ in 0..2 {
         result = convolve(&result, &algo);
     }
-    result = result.shrink(5, 5, 5, 5, false);
-    let num_light = result.data.iter().filter(|b| **b).count();
-    Ok(num_light.to_string())
+    result = result.crop(5);
+    let num_light = result.cells().iter().filter(|b| **b).count();
+    Ok(num_light.into())
 }
 
-pub fn part2(input: &[u8]) -> anyhow::Result<String> {
+pub fn part2(input: &[u8]) -> anyhow::Result<Answer> {
     let (algo, map) = parsers::parse(p_input, input)?;
     let num_enhancements = 50;
 
-    let mut result = map.pad(
-        num_enhancements * 2,
-        num_enhancements * 2,
-        num_enhancements * 2,
-        num_enhancements * 2,
-        false,
-    );
+    let mut result = map.pad(num_enhancements * 2, false);
     for _ in 0..num_enhancements {
         result = convolve(&result, &algo);
     }
-    result = result.shrink(
-        num_enhancements,
-        num_enhancements,
-        num_enhancements,
-        num_enhancements,
-        false,
-    );
-    let num_light = result.data.iter().filter(|b| **b).count();
-    Ok(num_light.to_string())
+    result = result.crop(num_enhancements);
+    let num_light = result.cells().iter().filter(|b| **b).count();
+    Ok(num_light.into())
 }
 
-fn convolve(map: &Map<bool>, kernel: &[bool]) -> Map<bool> {
-    let mut new_map = Map::new(map.width, map.height, false);
-    for y in 1..map.height - 1 {
-        for x in 1..map.width - 1 {
+fn convolve(map: &Grid<bool>, kernel: &[bool]) -> Grid<bool> {
+    let mut new_map = Grid::new(map.width(), map.height(), false);
+    for y in 1..map.height() - 1 {
+        for x in 1..map.width() - 1 {
             let mut index = 0;
             for dy in -1..=1 {
                 for dx in -1..=1 {
@@ -67,20 +52,8 @@ fn convolve(map: &Map<bool>, kernel: &[bool]) -> Map<bool> {
     new_map
 }
 
-fn render(map: &Map<bool>) -> String {
-    let mut out = String::new();
-    for y in 0..map.height {
-        for x in 0..map.width {
-            let ch = if map[(x, y)] { '#' } else { '.' };
-            out.push(ch);
-        }
-        out.push('\n');
-    }
-    out
-}
-
-fn p_input(input: &[u8]) -> IResult<&[u8], (Vec<bool>, Map<bool>)> {
-    separated_pair(p_algo, parsers::newline, p_map)(input)
+fn p_input(input: &[u8]) -> IResult<&[u8], (Vec<bool>, Grid<bool>)> {
+    separated_pair(p_algo, parsers::newline, parse_grid)(input)
 }
 
 fn p_algo(input: &[u8]) -> IResult<&[u8], Vec<bool>> {
@@ -96,97 +69,6 @@ fn p_algo(input: &[u8]) -> IResult<&[u8], Vec<bool>> {
     )(input)
 }
 
-fn p_map(input: &[u8]) -> IResult<&[u8], Map<bool>> {
-    flat_map(
-        terminated(take_while(|c| matches!(c, b'.' | b'#')), parsers::newline),
-        |first_line| {
-            let width = first_line.len();
-            fold_many0(
-                map_opt(
-                    terminated(take_while(|c| matches!(c, b'.' | b'#')), parsers::newline),
-                    move |line| {
-                        if line.len() == width {
-                            Some(line)
-                        } else {
-                            None
-                        }
-                    },
-                ),
-                move || Map {
-                    data: first_line.iter().map(|c| *c == b'#').collect(),
-                    width: width as u32,
-                    height: 1,
-                },
-                |mut map, line| {
-                    map.height += 1;
-                    map.data.extend(line.iter().map(|c| *c == b'#'));
-                    map
-                },
-            )
-        },
-    )(input)
-}
-
-#[derive(Debug)]
-struct Map<T> {
-    // Presumably no need to bother with Z-order curve here since whole data
-    // (100 bytes) fits into two cache lines already (typically 128 bytes).
-    data: Vec<T>,
-    width: u32,
-    height: u32,
-}
-
-impl<T> Map<T>
-where
-    T: Copy,
-{
-    pub fn new(width: u32, height: u32, value: T) -> Self {
-        Self {
-            data: vec![value; width as usize * height as usize],
-            width,
-            height,
-        }
-    }
-
-    pub fn pad(&self, left: u32, right: u32, top: u32, bottom: u32, value: T) -> Map<T> {
-        let mut new_map = Map::new(self.width + left + right, self.height + top + bottom, value);
-        for y in 0..self.height {
-            new_map.data[((y + top) * new_map.width + left) as usize
-                ..((y + top) * new_map.width + left + self.width) as usize]
-                .copy_from_slice(
-                    &self.data[(y * self.width) as usize..(y * self.width + self.width) as usize],
-                )
-        }
-        new_map
-    }
-
-    pub fn shrink(&self, left: u32, right: u32, top: u32, bottom: u32, value: T) -> Map<T> {
-        let mut new_map = Map::new(self.width - left - right, self.height - top - bottom, value);
-        for y in 0..new_map.height {
-            new_map.data[(y * new_map.width) as usize..(y * new_map.width + new_map.width) as usize]
-                .copy_from_slice(
-                    &self.data[((y + top) * self.width + left) as usize
-                        ..((y + top) * self.width + left + new_map.width) as usize],
-                )
-        }
-        new_map
-    }
-}
-
-impl<T> Index<(u32, u32)> for Map<T> {
-    type Output = T;
-
-    fn index(&self, index: (u32, u32)) -> &Self::Output {
-        &self.data[(index.0 + index.1 * self.width) as usize]
-    }
-}
-
-impl<T> IndexMut<(u32, u32)> for Map<T> {
-    fn index_mut(&mut self, index: (u32, u32)) -> &mut Self::Output {
-        &mut self.data[(index.0 + index.1 * self.width) as usize]
-    }
-}
-
 // TODO: implement truly infinite image:
 // struct Image {
 //     chunks: HashMap<(i32, i32), u64>,
@@ -268,5 +150,3 @@ impl<T> IndexMut<(u32, u32)> for Map<T> {
 //         assert_eq!(Image::chunk_row::<3>(chunk, 5, 1), 0b011);
 //     }
 // }
-
-crate::test_day!(crate::day20::RUN, "day20", "5464", "19228");
@@ -2,16 +2,16 @@
 
 use std::ops::{Index, IndexMut};
 
-use crate::{parsers, Day};
+use crate::{parsers, Answer, Day};
 use nom::bytes::complete::{tag, take_while};
 use nom::character::complete as numbers;
 use nom::combinator::{flat_map, map};
 use nom::multi::fold_many0;
 use nom::sequence::{pair, terminated, tuple};
 use nom::IResult;
-pub static RUN: Day = Day { part1, part2 };
+pub static RUN: Day = Day::new(part1, part2).titled("Dirac Dice");
 
-pub fn part1(input: &[u8]) -> anyhow::Result<String> {
+pub fn part1(input: &[u8]) -> anyhow::Result<Answer> {
     let (pos1, pos2) = parsers::parse(p_input, input)?;
 
     let mut positions = [pos1, pos2];
@@ -32,10 +32,10 @@ pub fn part1(input: &[u8]) -> anyhow::Result<String> {
 
     let result = die.rolls * scores.iter().copied().min().unwrap_or(0);
 
-    Ok(result.to_string())
+    Ok(result.into())
 }
 
-pub fn part2(input: &[u8]) -> anyhow::Result<String> {
+pub fn part2(input: &[u8]) -> anyhow::Result<Answer> {
     let (pos1, pos2) = parsers::parse(p_input, input)?;
 
     let mut state_space = DiracState::new();
@@ -143,7 +143,7 @@ pub fn part2(input: &[u8]) -> anyhow::Result<String> {
     //     }
     // }
 
-    Ok(p1_wins.max(p2_wins).to_string())
+    Ok(p1_wins.max(p2_wins).into())
 }
 
 fn p_input(input: &[u8]) -> IResult<&[u8], (u32, u32)> {
@@ -226,5 +226,3 @@ impl IndexMut<StateIndex> for DiracState {
         &mut self.states[index.offset()]
     }
 }
-
-crate::test_day!(crate::day21::RUN, "day21", "897798", "48868319769358");
@@ -4,22 +4,21 @@ use std::cmp::Reverse;
 use std::collections::BinaryHeap;
 use std::fmt::Display;
 use std::io::BufRead;
-use std::ops::{Index, IndexMut};
 
-use crate::{parsers, Day};
+use crate::{grid::Grid, parsers, Answer, Day};
 use anyhow::Context;
 use nom::bytes::complete::take_while;
 use nom::combinator::{flat_map, map};
 use nom::multi::fold_many0;
 use nom::sequence::terminated;
 use nom::IResult;
-pub static RUN: Day = Day { part1, part2 };
+pub static RUN: Day = Day::new(part1, part2).titled("Amphipod");
 
-pub fn part1(input: &[u8]) -> anyhow::Result<String> {
+pub fn part1(input: &[u8]) -> anyhow::Result<Answer> {
     let mut board = parse::<2>(input);
     let least_cost = solve_iter(&mut board);
 
-    Ok(least_cost.to_string())
+    Ok(least_cost.into())
 }
 
 fn solve_iter<const CAVE_HEIGHT: u32>(board: &mut Board<CAVE_HEIGHT>) -> u32 {
@@ -70,7 +69,7 @@ fn solve_iter<const CAVE_HEIGHT: u32>(board: &mut Board<CAVE_HEIGHT>) -> u32 {
     best_so_far
 }
 
-pub fn part2(input: &[u8]) -> anyhow::Result<String> {
+pub fn part2(input: &[u8]) -> anyhow::Result<Answer> {
     let (pos, _) = input
         .iter()
         .copied()
@@ -85,7 +84,7 @@ pub fn part2(input: &[u8]) -> anyhow::Result<String> {
     let mut board = parse::<4>(&modified_input);
     let least_cost = solve_iter(&mut board);
 
-    Ok(least_cost.to_string())
+    Ok(least_cost.into())
 }
 
 fn parse<const CAVE_HEIGHT: u32>(input: &[u8]) -> Board<CAVE_HEIGHT> {
@@ -164,11 +163,7 @@ fn manhattan((x1, y1): (u32, u32), (x2, y2): (u32, u32)) -> u32 {
 }
 
 fn absdiff(a: u32, b: u32) -> u32 {
-    if a < b {
-        b - a
-    } else {
-        a - b
-    }
+    b.abs_diff(a)
 }
 
 fn cost(color: Color, dist: u32) -> u32 {
@@ -183,9 +178,9 @@ fn cost(color: Color, dist: u32) -> u32 {
 
 #[derive(Debug)]
 struct Board<const CAVE_HEIGHT: u32> {
-    fields: Map<Option<Field>>,
+    fields: Grid<Option<Field>>,
     amphipods: Vec<(u32, u32)>,
-    podmap: Map<u8>,
+    podmap: Grid<u8>,
 }
 
 impl<const CAVE_HEIGHT: u32> Board<CAVE_HEIGHT> {
@@ -197,14 +192,14 @@ impl<const CAVE_HEIGHT: u32> Board<CAVE_HEIGHT> {
     ];
 
     pub fn new() -> Self {
-        let mut map = Map::new(13, 3 + CAVE_HEIGHT, None);
+        let mut map = Grid::new(13, 3 + CAVE_HEIGHT, None);
         for i in 1..=11 {
             map[(i, 1)] = Some(Field::Hallway);
         }
         Self {
             fields: map,
             amphipods: vec![],
-            podmap: Map::new(13, 3 + CAVE_HEIGHT, 0xFF),
+            podmap: Grid::new(13, 3 + CAVE_HEIGHT, 0xFF),
         }
     }
 
@@ -397,8 +392,8 @@ impl<const CAVE_HEIGHT: u32> Board<CAVE_HEIGHT> {
 
 impl<const CAVE_HEIGHT: u32> Display for Board<CAVE_HEIGHT> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for y in 0..self.fields.height {
-            for x in 0..self.fields.width {
+        for y in 0..self.fields.height() {
+            for x in 0..self.fields.width() {
                 write!(
                     f,
                     "{}",
@@ -414,43 +409,3 @@ impl<const CAVE_HEIGHT: u32> Display for Board<CAVE_HEIGHT> {
         Ok(())
     }
 }
-
-#[derive(Debug)]
-struct Map<T> {
-    // Presumably no need to bother with Z-order curve here since whole data
-    // (100 bytes) fits into two cache lines already (typically 128 bytes).
-    data: Vec<T>,
-    width: u32,
-    height: u32,
-}
-
-impl<T> Map<T>
-where
-    T: Copy,
-{
-    pub fn new(width: u32, height: u32, value: T) -> Self {
-        Self {
-            data: vec![value; width as usize * height as usize],
-            width,
-            height,
-        }
-    }
-}
-
-impl<T> Index<(u32, u32)> for Map<T> {
-    type Output = T;
-
-    #[inline]
-    fn index(&self, index: (u32, u32)) -> &Self::Output {
-        &self.data[(index.0 + index.1 * self.width) as usize]
-    }
-}
-
-impl<T> IndexMut<(u32, u32)> for Map<T> {
-    #[inline]
-    fn index_mut(&mut self, index: (u32, u32)) -> &mut Self::Output {
-        &mut self.data[(index.0 + index.1 * self.width) as usize]
-    }
-}
-
-crate::test_day!(crate::day23::RUN, "day23", "14460", "41366");
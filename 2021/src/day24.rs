@@ -6,7 +6,7 @@ use std::fmt::Display;
 use std::hash::Hash;
 use std::ops::{Add, Div, Mul, Rem};
 
-use crate::{parsers, Day};
+use crate::{parsers, Answer, Day};
 use nom::branch::alt;
 use nom::bytes::complete::{tag, take_while};
 use nom::character::complete as numbers;
@@ -15,23 +15,23 @@ use nom::combinator::{flat_map, map};
 use nom::multi::{fold_many0, many0};
 use nom::sequence::{preceded, terminated, tuple};
 use nom::IResult;
-pub static RUN: Day = Day { part1, part2 };
+pub static RUN: Day = Day::new(part1, part2).titled("Arithmetic Logic Unit");
 
-pub fn part1(input: &[u8]) -> anyhow::Result<String> {
+pub fn part1(input: &[u8]) -> anyhow::Result<Answer> {
     let validator = parsers::parse(p_prog, input)?;
 
     let input = find_input(&validator, (1..=9).rev());
     let result = input.into_iter().fold(0, |acc, d| acc * 10 + d);
 
-    Ok(result.to_string())
+    Ok(result.into())
 }
 
-pub fn part2(input: &[u8]) -> anyhow::Result<String> {
+pub fn part2(input: &[u8]) -> anyhow::Result<Answer> {
     let validator = parsers::parse(p_prog, input)?;
     let input = find_input(&validator, 1..=9);
     let result = input.into_iter().fold(0, |acc, d| acc * 10 + d);
 
-    Ok(result.to_string())
+    Ok(result.into())
 }
 
 pub fn find_input<I: Iterator<Item = i64> + Clone>(validator: &[Inst], set: I) -> Vec<i64> {
@@ -64,8 +64,8 @@ pub fn find_input<I: Iterator<Item = i64> + Clone>(validator: &[Inst], set: I) -
             states.push(cur_state.clone());
             cur_state.step_input(validator, cur);
 
-            for v in 0..4 {
-                if ! ranges[cur_state.ip][v].contains(cur_state.state[v]) {
+            for (range, value) in ranges[cur_state.ip].iter().zip(cur_state.state) {
+                if !range.contains(value) {
                     cur_state = states.pop().unwrap();
                     continue 'outer;
                 }
@@ -289,7 +289,7 @@ fn range_analysis(prog: &[Inst], input: RangeVal) -> Vec<[RangeVal; 4]> {
 fn restrict_ranges(prog: &[Inst], ranges: &mut [[RangeVal; 4]]) {
     assert!(ranges.len() == prog.len() + 1);
 
-    for (ip, inst) in prog.into_iter().enumerate().rev() {
+    for (ip, inst) in prog.iter().enumerate().rev() {
         let after = ranges[ip + 1];
         let before = &mut ranges[ip];
 
@@ -320,10 +320,7 @@ fn restrict_ranges(prog: &[Inst], ranges: &mut [[RangeVal; 4]]) {
                 let out = after[a.index()];
                 if *b > 0 {
                     before[a.index()] = before[a.index()]
-                        .restrict(RangeVal::inclusive(
-                            out.from * b,
-                            out.to * b + (b - 1),
-                        ))
+                        .restrict(RangeVal::inclusive(out.from * b, out.to * b + (b - 1)))
                         .unwrap();
                 } else {
                     // TODO: For a complete implementation, the other cases should also be
@@ -406,7 +403,7 @@ fn debug_print_ranges(
     }
 
     print_range_state(out, ranges[0])?;
-    for (inst, state) in prog.into_iter().zip(ranges[1..].into_iter()) {
+    for (inst, state) in prog.iter().zip(ranges[1..].iter()) {
         writeln!(out, "  {:?}", inst)?;
         print_range_state(out, *state)?;
     }
@@ -526,7 +523,7 @@ pub enum Inst {
 }
 
 impl Inst {
-    pub fn out_var(&self) -> Var{
+    pub fn out_var(&self) -> Var {
         match *self {
             Inst::Inp(v) => v,
             Inst::Add(v, _) => v,
@@ -689,10 +686,3 @@ mod w 2
         assert_eq!(st.state, [0, 1, 0, 1]);
     }
 }
-
-crate::test_day!(
-    crate::day24::RUN,
-    "day24",
-    "74929995999389",
-    "11118151637112"
-);
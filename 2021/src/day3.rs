@@ -4,12 +4,12 @@ use nom::multi::fold_many0;
 use nom::sequence::terminated;
 use nom::IResult;
 
-use crate::{parsers, Day};
+use crate::{parsers, Answer, Day};
 use std::cmp::Ordering;
 
-pub static RUN: Day = Day { part1, part2 };
+pub static RUN: Day = Day::new(part1, part2).titled("Binary Diagnostic");
 
-pub fn part1(input: &[u8]) -> anyhow::Result<String> {
+pub fn part1(input: &[u8]) -> anyhow::Result<Answer> {
     parsers::parse(
         flat_map(
             map(
@@ -34,7 +34,7 @@ pub fn part1(input: &[u8]) -> anyhow::Result<String> {
                     ),
                     |counts| {
                         let (epsilon, gamma) = counts.epsilon_gamma();
-                        format!("{}", epsilon * gamma)
+                        Answer::from(epsilon * gamma)
                     },
                 )
             },
@@ -43,7 +43,7 @@ pub fn part1(input: &[u8]) -> anyhow::Result<String> {
     )
 }
 
-pub fn part2(input: &[u8]) -> anyhow::Result<String> {
+pub fn part2(input: &[u8]) -> anyhow::Result<Answer> {
     parsers::parse(
         flat_map(terminated(BinaryLen::parse, parsers::newline), |first| {
             map(
@@ -66,7 +66,7 @@ pub fn part2(input: &[u8]) -> anyhow::Result<String> {
                     let o2 = o2_candidates[0];
                     let co2 = co2_candidates[0];
 
-                    format!("{}", o2 * co2)
+                    Answer::from(o2 * co2)
                 },
             )
         }),
@@ -163,5 +163,3 @@ pub fn parse_bin(input: &[u8]) -> IResult<&[u8], u32> {
             .fold(0, |acc, digit| acc << 1 | digit as u32)
     })(input)
 }
-
-crate::test_day!(crate::day3::RUN, "day3", "4147524", "3570354");
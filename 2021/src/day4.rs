@@ -7,11 +7,11 @@ use nom::{
     IResult,
 };
 
-use crate::{parsers, Day};
+use crate::{parsers, Answer, Day};
 
-pub static RUN: Day = Day { part1, part2 };
+pub static RUN: Day = Day::new(part1, part2).titled("Giant Squid");
 
-pub fn part1(input: &[u8]) -> anyhow::Result<String> {
+pub fn part1(input: &[u8]) -> anyhow::Result<Answer> {
     let mut bingo = parsers::parse(p_bingo, input)?;
 
     let mut win = None;
@@ -27,13 +27,13 @@ pub fn part1(input: &[u8]) -> anyhow::Result<String> {
     }
 
     if let Some((sum, last_draw)) = win {
-        Ok(format!("{}", sum * last_draw))
+        Ok((sum * last_draw).into())
     } else {
         bail!("no win")
     }
 }
 
-pub fn part2(input: &[u8]) -> anyhow::Result<String> {
+pub fn part2(input: &[u8]) -> anyhow::Result<Answer> {
     let mut bingo = parsers::parse(p_bingo, input)?;
 
     let mut last_win = None;
@@ -56,7 +56,7 @@ pub fn part2(input: &[u8]) -> anyhow::Result<String> {
     }
 
     if let Some((sum, last_draw)) = last_win {
-        Ok(format!("{}", sum * last_draw))
+        Ok((sum * last_draw).into())
     } else {
         bail!("no win")
     }
@@ -189,5 +189,3 @@ impl Board {
             .1
     }
 }
-
-crate::test_day!(crate::day4::RUN, "day4", "72770", "13912");
@@ -4,11 +4,11 @@ use nom::multi::many0;
 use nom::sequence::{separated_pair, terminated};
 use nom::IResult;
 
-use crate::{parsers, Day};
+use crate::{parsers, Answer, Day};
 
-pub static RUN: Day = Day { part1, part2 };
+pub static RUN: Day = Day::new(part1, part2).titled("Hydrothermal Venture");
 
-pub fn part1(input: &[u8]) -> anyhow::Result<String> {
+pub fn part1(input: &[u8]) -> anyhow::Result<Answer> {
     let lines = parsers::parse(many0(terminated(Line::parse, parsers::newline)), input)?;
 
     let (max_x, max_y) = lines_extent(&lines);
@@ -21,10 +21,10 @@ pub fn part1(input: &[u8]) -> anyhow::Result<String> {
         }
     }
 
-    Ok(format!("{}", map.count_danger()))
+    Ok(map.count_danger().into())
 }
 
-pub fn part2(input: &[u8]) -> anyhow::Result<String> {
+pub fn part2(input: &[u8]) -> anyhow::Result<Answer> {
     let lines = parsers::parse(many0(terminated(Line::parse, parsers::newline)), input)?;
 
     let (max_x, max_y) = lines_extent(&lines);
@@ -35,7 +35,7 @@ pub fn part2(input: &[u8]) -> anyhow::Result<String> {
         line.points().for_each(|p| map.add_point(p));
     }
 
-    Ok(format!("{}", map.count_danger()))
+    Ok(map.count_danger().into())
 }
 
 fn lines_extent(lines: &[Line]) -> (i32, i32) {
@@ -159,5 +159,3 @@ impl Line {
             .take(steps as usize + 1)
     }
 }
-
-crate::test_day!(crate::day5::RUN, "day5", "7644", "18627");
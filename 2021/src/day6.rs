@@ -6,20 +6,28 @@ use nom::multi::{fold_many0, separated_list0};
 use nom::sequence::terminated;
 use nom::IResult;
 
-use crate::{parsers, Day};
+use crate::{
+    params::{Param, Params},
+    parsers, Answer, Day,
+};
 use std::cmp::Ordering;
 
-pub static RUN: Day = Day { part1, part2 };
+pub static RUN: Day = Day::with_params(
+    &[Param::new("part1_days", 80), Param::new("part2_days", 256)],
+    part1,
+    part2,
+)
+.titled("Lanternfish");
 
-pub fn part1(input: &[u8]) -> anyhow::Result<String> {
-    parse_and_sim(input, 80)
+pub fn part1(input: &[u8], params: &Params) -> anyhow::Result<Answer> {
+    parse_and_sim(input, params.get("part1_days")?)
 }
 
-pub fn part2(input: &[u8]) -> anyhow::Result<String> {
-    parse_and_sim(input, 256)
+pub fn part2(input: &[u8], params: &Params) -> anyhow::Result<Answer> {
+    parse_and_sim(input, params.get("part2_days")?)
 }
 
-fn parse_and_sim(input: &[u8], num_days: u32) -> anyhow::Result<String> {
+fn parse_and_sim(input: &[u8], num_days: u32) -> anyhow::Result<Answer> {
     let fish_ages = parsers::parse(
         terminated(
             separated_list0(tag(","), parsers::u32),
@@ -27,7 +35,7 @@ fn parse_and_sim(input: &[u8], num_days: u32) -> anyhow::Result<String> {
         ),
         input,
     )?;
-    Ok(format!("{}", simulate(&fish_ages, num_days)))
+    Ok(simulate(&fish_ages, num_days).into())
 }
 
 fn simulate(fish_ages: &[u32], num_days: u32) -> u64 {
@@ -85,5 +93,3 @@ impl Population {
         }
     }
 }
-
-crate::test_day!(crate::day6::RUN, "day6", "395627", "1767323539209");
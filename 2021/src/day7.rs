@@ -1,27 +1,27 @@
 #![allow(unused_imports)]
 
-use crate::{parsers, Day};
+use crate::{parsers, Answer, Day};
 use nom::bytes::complete::{tag, take_while};
 use nom::combinator::{flat_map, map};
 use nom::multi::{fold_many0, separated_list0};
 use nom::sequence::terminated;
 use nom::IResult;
-pub static RUN: Day = Day { part1, part2 };
+pub static RUN: Day = Day::new(part1, part2).titled("The Treachery of Whales");
 
-pub fn part1(input: &[u8]) -> anyhow::Result<String> {
+pub fn part1(input: &[u8]) -> anyhow::Result<Answer> {
     let crab_counts = parse_crabs(input)?;
 
     let best_fuel = compute_fuel::<2>(&crab_counts);
 
-    Ok(format!("{}", best_fuel))
+    Ok(best_fuel.into())
 }
 
-pub fn part2(input: &[u8]) -> anyhow::Result<String> {
+pub fn part2(input: &[u8]) -> anyhow::Result<Answer> {
     let crab_counts = parse_crabs(input)?;
 
     let best_fuel = compute_fuel::<3>(&crab_counts);
 
-    Ok(format!("{}", best_fuel))
+    Ok(best_fuel.into())
 }
 
 fn compute_fuel<const ORDER: usize>(crab_counts: &[u32]) -> u64 {
@@ -77,5 +77,3 @@ fn parse_crabs(input: &[u8]) -> Result<Vec<u32>, anyhow::Error> {
     }
     Ok(crab_counts)
 }
-
-crate::test_day!(crate::day7::RUN, "day7", "347509", "98257206");
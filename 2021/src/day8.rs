@@ -2,16 +2,16 @@
 
 use std::fmt::Display;
 
-use crate::{parsers, Day};
+use crate::{parsers, Answer, Day};
 use anyhow::Context;
 use nom::bytes::complete::{tag, take_while};
 use nom::combinator::{all_consuming, flat_map, map, map_opt};
 use nom::multi::{fold_many0, fold_many1, many0, many_m_n};
 use nom::sequence::{preceded, separated_pair, terminated};
 use nom::IResult;
-pub static RUN: Day = Day { part1, part2 };
+pub static RUN: Day = Day::new(part1, part2).titled("Seven Segment Search");
 
-pub fn part1(input: &[u8]) -> anyhow::Result<String> {
+pub fn part1(input: &[u8]) -> anyhow::Result<Answer> {
     let entries = parsers::parse(many0(terminated(p_entry, parsers::newline)), input)?;
 
     // 1: 2 segments
@@ -25,10 +25,10 @@ pub fn part1(input: &[u8]) -> anyhow::Result<String> {
         .filter(|p| matches!(p.count_set(), 2 | 3 | 4 | 7))
         .count();
 
-    Ok(format!("{}", num_unique))
+    Ok(num_unique.into())
 }
 
-pub fn part2(input: &[u8]) -> anyhow::Result<String> {
+pub fn part2(input: &[u8]) -> anyhow::Result<Answer> {
     let entries = parsers::parse(many0(terminated(p_entry, parsers::newline)), input)?;
 
     let mut result = 0;
@@ -46,7 +46,7 @@ pub fn part2(input: &[u8]) -> anyhow::Result<String> {
         result += num;
     }
 
-    Ok(format!("{}", result))
+    Ok(result.into())
 }
 
 fn p_pattern(input: &[u8]) -> IResult<&[u8], Pattern> {
@@ -285,5 +285,3 @@ impl Display for Pattern {
         Ok(())
     }
 }
-
-crate::test_day!(crate::day8::RUN, "day8", "278", "986179");
@@ -1,160 +1,44 @@
-use std::{
-    io::{BufRead, BufReader, Read},
-    marker::PhantomData,
-    str::FromStr,
-};
-
-pub mod day1;
-pub mod day2;
-pub mod day3;
-pub mod day4;
-pub mod day5;
-pub mod day6;
-pub mod day7;
-pub mod day8;
-pub mod day9;
-
-pub mod day10;
-pub mod day11;
-pub mod day12;
-pub mod day13;
-pub mod day14;
-pub mod day15;
-pub mod day16;
-pub mod day17;
-pub mod day18;
-pub mod day19;
-
-pub mod day20;
-pub mod day21;
-pub mod day22;
-pub mod day23;
-pub mod day24;
-pub mod day25;
-
-pub mod parsers;
-pub mod runner;
-pub use runner::{aoc_main, Day};
-
-pub struct FileParser<R> {
-    file: BufReader<R>,
-    buffer: String,
-    error: Option<std::io::Error>,
-}
-
-impl<R: Read> FileParser<R> {
-    pub fn new(file: R) -> Self {
-        Self {
-            file: BufReader::new(file),
-            buffer: String::new(),
-            error: None,
-        }
-    }
-
-    pub fn iter_parse<T>(&mut self) -> ParseIter<R, T> {
-        ParseIter {
-            parser: self,
-            output: PhantomData,
-        }
-    }
-
-    pub fn parse_line<T: FromStr>(&mut self) -> Option<T> {
-        self.buffer.clear();
-        match self.file.read_line(&mut self.buffer) {
-            Ok(n) => {
-                if n == 0 {
-                    None
-                } else {
-                    match self.buffer.trim_end_matches('\n').parse::<T>() {
-                        Ok(val) => Some(val),
-                        Err(_err) => {
-                            self.error = Some(std::io::Error::new(
-                                std::io::ErrorKind::InvalidData,
-                                "no parse",
-                            ));
-                            None
-                        }
-                    }
-                }
-            }
-            Err(err) => {
-                self.error = Some(err);
-                None
-            }
-        }
-    }
-
-    pub fn read_line(&mut self) -> Option<&str> {
-        self.buffer.clear();
-        match self.file.read_line(&mut self.buffer) {
-            Ok(n) => {
-                if n == 0 {
-                    None
-                } else {
-                    Some(self.buffer.trim_end_matches('\n'))
-                }
-            }
-            Err(err) => {
-                self.error = Some(err);
-                None
-            }
-        }
-    }
-
-    pub fn finish(self) -> std::io::Result<()> {
-        match self.error {
-            Some(err) => Err(err),
-            None => Ok(()),
-        }
-    }
-}
-
-pub struct ParseIter<'a, R, T> {
-    parser: &'a mut FileParser<R>,
-    output: PhantomData<T>,
-}
-
-impl<'a, R, T> Iterator for ParseIter<'a, R, T>
-where
-    R: Read,
-    T: FromStr,
-{
-    type Item = T;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        self.parser.parse_line()
-    }
+aoc_core::days! {
+    day1,
+    day2,
+    day3,
+    day4,
+    day5,
+    day6,
+    day7,
+    day8,
+    day9,
+    day10,
+    day11,
+    day12,
+    day13,
+    day14,
+    day15,
+    day16,
+    day17,
+    day18,
+    day19,
+    day20,
+    day21,
+    day22,
+    day23,
+    day24,
+    day25,
 }
 
-#[macro_export]
-macro_rules! include_input {
-    ($day:expr) => {
-        include_bytes!(concat!(
-            env!("CARGO_MANIFEST_DIR"),
-            "/inputs/",
-            $day,
-            "/input.txt"
-        ))
-    };
-}
+pub use aoc_core::{
+    answer, aoc_main, cycle, grid, interval, params, parsers, runner, search, vector, Answer, Day,
+    Year,
+};
 
-#[macro_export]
-macro_rules! test_day {
-    ($day:expr, $name:expr, $part1:expr, $part2:expr) => {
-        #[cfg(test)]
-        mod test {
-            const INPUT: &[u8] = crate::include_input!($name);
+pub static YEAR: Year = Year {
+    year: 2021,
+    days: DAYS,
+    inputs: concat!(env!("CARGO_MANIFEST_DIR"), "/inputs"),
+    src: concat!(env!("CARGO_MANIFEST_DIR"), "/src"),
+};
 
-            #[test]
-            fn part1() {
-                let output1 = ($day.part1)(INPUT).expect("part 1 should work");
-                assert_eq!(output1, $part1, "part 1");
-            }
-            #[test]
-            fn part2() {
-                let output2 = ($day.part2)(&mut INPUT.as_ref()).expect("part 2 should work");
-                assert_eq!(output2, $part2, "part 2");
-            }
-        }
-    };
+#[cfg(test)]
+mod input_tests {
+    include!(concat!(env!("OUT_DIR"), "/input_tests.rs"));
 }
@@ -1,31 +1,8 @@
-use advent_of_code_2021::{self as aoc, aoc_main};
+use advent_of_code_2021::{aoc_main, YEAR};
+
+#[global_allocator]
+static ALLOCATOR: aoc_core::memory::CountingAllocator = aoc_core::memory::CountingAllocator;
 
 fn main() -> anyhow::Result<()> {
-    aoc_main(&[
-        aoc::day1::RUN,
-        aoc::day2::RUN,
-        aoc::day3::RUN,
-        aoc::day4::RUN,
-        aoc::day5::RUN,
-        aoc::day6::RUN,
-        aoc::day7::RUN,
-        aoc::day8::RUN,
-        aoc::day9::RUN,
-        aoc::day10::RUN,
-        aoc::day11::RUN,
-        aoc::day12::RUN,
-        aoc::day13::RUN,
-        aoc::day14::RUN,
-        aoc::day15::RUN,
-        aoc::day16::RUN,
-        aoc::day17::RUN,
-        aoc::day18::RUN,
-        aoc::day19::RUN,
-        aoc::day20::RUN,
-        aoc::day21::RUN,
-        aoc::day22::RUN,
-        aoc::day23::RUN,
-        aoc::day24::RUN,
-        aoc::day25::RUN,
-    ])
+    aoc_main(&[YEAR])
 }
@@ -2,12 +2,12 @@ use nom::{character::complete::char, multi::fold_many1, sequence::terminated};
 
 use crate::{
     parsers::{self, newline_or_eof},
-    Day,
+    Answer, Day,
 };
 
-pub static RUN: Day = Day { part1, part2 };
+pub static RUN: Day = Day::new(part1, part2).titled("Calorie Counting");
 
-pub fn part1(input: &[u8]) -> anyhow::Result<String> {
+pub fn part1(input: &[u8]) -> anyhow::Result<Answer> {
     let max = parsers::parse(
         fold_many1(
             terminated(parse_inventory, newline_or_eof),
@@ -16,10 +16,10 @@ pub fn part1(input: &[u8]) -> anyhow::Result<String> {
         ),
         input,
     )?;
-    Ok(max.to_string())
+    Ok(max.into())
 }
 
-pub fn part2(input: &[u8]) -> anyhow::Result<String> {
+pub fn part2(input: &[u8]) -> anyhow::Result<Answer> {
     let top3 = parsers::parse(
         fold_many1(
             terminated(parse_inventory, newline_or_eof),
@@ -32,7 +32,7 @@ pub fn part2(input: &[u8]) -> anyhow::Result<String> {
         ),
         input,
     )?;
-    Ok(top3.iter().sum::<u64>().to_string())
+    Ok(top3.iter().sum::<u64>().into())
 }
 
 fn parse_inventory(input: &[u8]) -> nom::IResult<&[u8], u64> {
@@ -50,5 +50,3 @@ fn test_parse_inventory() {
 
     assert_eq!(parse_inventory(b"10\n\n"), Ok((b"\n".as_slice(), 10u64)));
 }
-
-crate::test_day!(RUN, "day1", "67027", "197291");
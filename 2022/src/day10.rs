@@ -7,11 +7,11 @@ use nom::{
     IResult,
 };
 
-use crate::{parsers, Day};
+use crate::{answer::Image, parsers, Answer, Day};
 
-pub static RUN: Day = Day { part1, part2 };
+pub static RUN: Day = Day::new(part1, part2).titled("Cathode-Ray Tube");
 
-pub fn part1(input: &[u8]) -> anyhow::Result<String> {
+pub fn part1(input: &[u8]) -> anyhow::Result<Answer> {
     let probes = [20, 60, 100, 140, 180, 220];
 
     let (_, _, sum) = parsers::parse(
@@ -32,10 +32,10 @@ pub fn part1(input: &[u8]) -> anyhow::Result<String> {
         input,
     )?;
 
-    Ok(sum.to_string())
+    Ok(sum.into())
 }
 
-pub fn part2(input: &[u8]) -> anyhow::Result<String> {
+pub fn part2(input: &[u8]) -> anyhow::Result<Answer> {
     let mut crt = Crt::new();
 
     parsers::parse(
@@ -49,7 +49,7 @@ pub fn part2(input: &[u8]) -> anyhow::Result<String> {
         input,
     )?;
 
-    Ok(crt.render())
+    Ok(crt.render().into())
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -130,27 +130,7 @@ impl Crt {
         self.cpu = new_state;
     }
 
-    fn render(&self) -> String {
-        let mut out = String::new();
-        for row in self.buf.chunks(Self::WIDTH) {
-            for col in row {
-                out.push(if *col { '#' } else { '.' });
-            }
-            out.push('\n');
-        }
-        out
+    fn render(&self) -> Image {
+        Image::new(Self::WIDTH, self.buf.clone())
     }
 }
-
-crate::test_day!(
-    RUN,
-    "day10",
-    "13180",
-    r"####.####.####..##..#..#...##..##..###..
-#.......#.#....#..#.#..#....#.#..#.#..#.
-###....#..###..#....####....#.#..#.###..
-#.....#...#....#....#..#....#.####.#..#.
-#....#....#....#..#.#..#.#..#.#..#.#..#.
-####.####.#.....##..#..#..##..#..#.###..
-"
-);
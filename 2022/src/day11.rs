@@ -10,16 +10,16 @@ use nom::{
     IResult,
 };
 
-use crate::{parsers, Day};
+use crate::{parsers, Answer, Day};
 
-pub static RUN: Day = Day { part1, part2 };
+pub static RUN: Day = Day::new(part1, part2).titled("Monkey in the Middle");
 
-pub fn part1(input: &[u8]) -> anyhow::Result<String> {
+pub fn part1(input: &[u8]) -> anyhow::Result<Answer> {
     let monkeys = parsers::parse(separated_list0(tag("\n"), parse_monkey), input)?;
     monkey_business(&monkeys, 20, |worry| worry / 3)
 }
 
-pub fn part2(input: &[u8]) -> anyhow::Result<String> {
+pub fn part2(input: &[u8]) -> anyhow::Result<Answer> {
     let monkeys = parsers::parse(separated_list0(tag("\n"), parse_monkey), input)?;
 
     let common_mod = monkeys
@@ -34,7 +34,7 @@ fn monkey_business(
     monkeys: &[Monkey],
     rounds: usize,
     anxiety_meds: impl Fn(u64) -> u64,
-) -> anyhow::Result<String> {
+) -> anyhow::Result<Answer> {
     let mut items: Vec<_> = monkeys.iter().map(|m| m.starting_items.clone()).collect();
     let mut inspections: Vec<usize> = vec![0; items.len()];
     let mut inspecting = Vec::new();
@@ -46,7 +46,7 @@ fn monkey_business(
 
             for item_worry in inspecting.drain(..) {
                 let new_worry = anxiety_meds(m.op.eval(item_worry));
-                let target = m.test.eval(new_worry) as usize;
+                let target = m.test.eval(new_worry);
                 items[target].push(new_worry);
             }
         }
@@ -54,7 +54,7 @@ fn monkey_business(
 
     let (top, second, _rest) = inspections.select_nth_unstable_by_key(1, |count| Reverse(*count));
 
-    Ok((top[0] * *second).to_string())
+    Ok((top[0] * *second).into())
 }
 
 fn parse_monkey(input: &[u8]) -> IResult<&[u8], Monkey> {
@@ -170,5 +170,3 @@ impl Operand {
         }
     }
 }
-
-crate::test_day!(RUN, "day11", "54054", "14314925001");
@@ -12,12 +12,12 @@ use nom::{
 
 use crate::{
     parsers::{self, newline},
-    Day,
+    Answer, Day,
 };
 
-pub static RUN: Day = Day { part1, part2 };
+pub static RUN: Day = Day::new(part1, part2).titled("Distress Signal");
 
-pub fn part1(input: &[u8]) -> anyhow::Result<String> {
+pub fn part1(input: &[u8]) -> anyhow::Result<Answer> {
     let (sum, _) = parsers::parse(
         fold_many0(
             terminated(parse_pair, opt(newline)),
@@ -35,10 +35,10 @@ pub fn part1(input: &[u8]) -> anyhow::Result<String> {
         ),
         input,
     )?;
-    Ok(sum.to_string())
+    Ok(sum.into())
 }
 
-pub fn part2(input: &[u8]) -> anyhow::Result<String> {
+pub fn part2(input: &[u8]) -> anyhow::Result<Answer> {
     let mut packets = parsers::parse(
         many0(terminated(parse_val, take_while1(|ch| ch == b'\n'))),
         input,
@@ -60,7 +60,7 @@ pub fn part2(input: &[u8]) -> anyhow::Result<String> {
         .expect("dividers should still be there")
         + 1;
 
-    Ok((pos1 * pos2).to_string())
+    Ok((pos1 * pos2).into())
 }
 
 fn parse_val(input: &[u8]) -> IResult<&[u8], Val> {
@@ -121,5 +121,3 @@ impl PartialOrd for Val {
 }
 
 // TODO: try defining order predicate directly on input strings/on more efficient representation
-
-crate::test_day!(RUN, "day13", "5252", "20592");
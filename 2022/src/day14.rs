@@ -1,10 +1,5 @@
 #![allow(unused)]
 
-use std::{
-    fmt::{Display, Write},
-    ops::{Index, IndexMut},
-};
-
 use anyhow::bail;
 use nom::{
     bytes::complete::tag,
@@ -15,22 +10,23 @@ use nom::{
 };
 
 use crate::{
+    grid::{Grid, Pos, Symbol},
     parsers::{self, newline},
-    Day,
+    Answer, Day,
 };
 
-pub static RUN: Day = Day { part1, part2 };
+pub static RUN: Day = Day::new(part1, part2).titled("Regolith Reservoir");
 
-pub fn part1(input: &[u8]) -> anyhow::Result<String> {
+pub fn part1(input: &[u8]) -> anyhow::Result<Answer> {
     let paths = parsers::parse(many0(terminated(parse_path, newline)), input)?;
     let (width, height) = paths
         .iter()
         .flat_map(|p| p.iter())
         .fold((501, 0), |(w, h), pos| (w.max(pos.x + 1), h.max(pos.y + 1)));
 
-    let mut map = Map::new(width, height, Cell::Air);
+    let mut map = Grid::new(width, height, Cell::Air);
     for path in paths.iter() {
-        map.draw_path(path, Cell::Rock)
+        draw_path(&mut map, path, Cell::Rock)
     }
 
     let mut count = 0;
@@ -38,10 +34,10 @@ pub fn part1(input: &[u8]) -> anyhow::Result<String> {
         count += 1
     }
 
-    Ok(count.to_string())
+    Ok(count.into())
 }
 
-pub fn part2(input: &[u8]) -> anyhow::Result<String> {
+pub fn part2(input: &[u8]) -> anyhow::Result<Answer> {
     let mut paths = parsers::parse(many0(terminated(parse_path, newline)), input)?;
     let (maxx, maxy) = paths
         .iter()
@@ -56,7 +52,7 @@ pub fn part2(input: &[u8]) -> anyhow::Result<String> {
     let min_sand_x = 500 - height as i32;
 
     // correct for negativev coordinates
-    let offsetx = if height > 500 { height - 500 } else { 0 };
+    let offsetx = height.saturating_sub(500);
 
     let width = maxx.max(max_sand_x) + 1 + offsetx;
     let origin = Pos {
@@ -70,12 +66,12 @@ pub fn part2(input: &[u8]) -> anyhow::Result<String> {
         }
     }
 
-    let mut map = Map::new(width, height, Cell::Air);
+    let mut map = Grid::new(width, height, Cell::Air);
     for path in paths.iter() {
-        map.draw_path(path, Cell::Rock)
+        draw_path(&mut map, path, Cell::Rock)
     }
     // draw floor
-    for x in 0..map.width {
+    for x in 0..map.width() {
         map[Pos { x, y: height - 1 }] = Cell::Rock;
     }
 
@@ -87,7 +83,7 @@ pub fn part2(input: &[u8]) -> anyhow::Result<String> {
         count += 1
     }
 
-    Ok(count.to_string())
+    Ok(count.into())
 }
 
 fn parse_pos(input: &[u8]) -> IResult<&[u8], Pos> {
@@ -118,7 +114,7 @@ enum Fall {
     Blocked,
 }
 
-fn propagate_sand(map: &mut Map<Cell>, origin: Pos) -> Fall {
+fn propagate_sand(map: &mut Grid<Cell>, origin: Pos) -> Fall {
     if !matches!(map[origin], Cell::Air) {
         return Fall::Blocked;
     }
@@ -152,12 +148,6 @@ fn propagate_sand(map: &mut Map<Cell>, origin: Pos) -> Fall {
     Fall::Abyss
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-struct Pos {
-    x: u32,
-    y: u32,
-}
-
 type Path = Vec<Pos>;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -167,79 +157,41 @@ enum Cell {
     Sand,
 }
 
-struct Map<T> {
-    data: Vec<T>,
-    width: u32,
-    height: u32,
-}
-
-impl<T: Clone> Map<T> {
-    fn new(width: u32, height: u32, init: T) -> Self {
-        Map {
-            data: vec![init; width as usize * height as usize],
-            width,
-            height,
-        }
-    }
-
-    fn offset(&self, x: u32, y: u32) -> usize {
-        (x as usize) + (self.width as usize) * (y as usize)
-    }
-
-    fn draw_path(&mut self, path: &Path, color: T) {
-        for (from, to) in path.iter().zip(path.iter().skip(1)) {
-            self.draw_line(*from, *to, color.clone());
+impl Symbol for Cell {
+    fn from_byte(byte: u8) -> Option<Self> {
+        match byte {
+            b'#' => Some(Cell::Rock),
+            b'.' => Some(Cell::Air),
+            b'o' => Some(Cell::Sand),
+            _ => None,
         }
     }
 
-    fn draw_line(&mut self, from: Pos, to: Pos, color: T) {
-        if from.x == to.x {
-            let x = from.x;
-            for y in from.y.min(to.y)..=from.y.max(to.y) {
-                self[Pos { x, y }] = color.clone();
-            }
-        } else {
-            let y = from.y;
-            for x in from.x.min(to.x)..=from.x.max(to.x) {
-                self[Pos { x, y }] = color.clone();
-            }
+    fn to_char(&self) -> char {
+        match self {
+            Cell::Rock => '#',
+            Cell::Air => '.',
+            Cell::Sand => 'o',
         }
     }
-
-    fn contains(&self, pos: Pos) -> bool {
-        pos.x < self.width && pos.y < self.height
-    }
-}
-
-impl<T: Clone> Index<Pos> for Map<T> {
-    type Output = T;
-
-    fn index(&self, index: Pos) -> &Self::Output {
-        &self.data[self.offset(index.x, index.y)]
-    }
 }
 
-impl<T: Clone> IndexMut<Pos> for Map<T> {
-    fn index_mut(&mut self, index: Pos) -> &mut Self::Output {
-        let offset = self.offset(index.x, index.y);
-        &mut self.data[offset]
+fn draw_path<T: Clone>(map: &mut Grid<T>, path: &Path, color: T) {
+    for (from, to) in path.iter().zip(path.iter().skip(1)) {
+        draw_line(map, *from, *to, color.clone());
     }
 }
 
-impl Display for Map<Cell> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for row in self.data.chunks(self.width as usize) {
-            for col in row {
-                f.write_char(match col {
-                    Cell::Rock => '#',
-                    Cell::Air => '.',
-                    Cell::Sand => 'o',
-                })?;
-            }
-            f.write_char('\n')?;
+fn draw_line<T: Clone>(map: &mut Grid<T>, from: Pos, to: Pos, color: T) {
+    if from.x == to.x {
+        let x = from.x;
+        for y in from.y.min(to.y)..=from.y.max(to.y) {
+            map[Pos { x, y }] = color.clone();
+        }
+    } else {
+        let y = from.y;
+        for x in from.x.min(to.x)..=from.x.max(to.x) {
+            map[Pos { x, y }] = color.clone();
         }
-        Ok(())
     }
 }
-
-crate::test_day!(RUN, "day14", "1298", "25585");
@@ -11,21 +11,28 @@ use nom::{
 };
 
 use crate::{
+    interval::{Interval, IntervalSet},
+    params::{Param, Params},
     parsers::{self, newline},
-    Day,
+    Answer, Day,
 };
 
-pub static RUN: Day = Day { part1, part2 };
+pub static RUN: Day = Day::with_params(
+    &[Param::new("row", 2000000), Param::new("max_coord", 4000000)],
+    part1,
+    part2,
+)
+.titled("Beacon Exclusion Zone");
 
-pub fn part1(input: &[u8]) -> anyhow::Result<String> {
+pub fn part1(input: &[u8], params: &Params) -> anyhow::Result<Answer> {
     let sensors = parsers::parse(many0(terminated(parse_sensor, newline)), input)?;
 
-    let result = count_row(&sensors, 2000000)?;
-    Ok(result.to_string())
+    let result = count_row(&sensors, params.get("row")?)?;
+    Ok(result.into())
 }
 
 fn count_row(sensors: &[Sensor], row: i32) -> anyhow::Result<usize> {
-    let mut blocked = Vec::new();
+    let mut blocked = IntervalSet::new();
     let mut beacon_xs = HashSet::new();
 
     for s in sensors.iter() {
@@ -36,31 +43,19 @@ fn count_row(sensors: &[Sensor], row: i32) -> anyhow::Result<usize> {
             if s.beacon.y == row {
                 beacon_xs.insert(s.beacon.x);
             }
-            blocked.push((s.position.x - rx as i32, s.position.x + rx as i32 + 1));
+            blocked.insert(Interval::inclusive(
+                s.position.x - rx as i32,
+                s.position.x + rx as i32,
+            ));
         }
     }
 
-    blocked.sort_unstable_by_key(|(from, _)| *from);
-
-    // coalesce overlapping intervals
-    let mut count = 0;
-    let mut current = blocked[0];
-    for next in &blocked[1..] {
-        if next.0 > current.1 {
-            count += (current.1 - current.0) as usize;
-            current = *next;
-        } else {
-            current.1 = current.1.max(next.1);
-        }
-    }
-    count += (current.1 - current.0) as usize;
-
-    Ok(count - beacon_xs.len())
+    Ok(blocked.len() as usize - beacon_xs.len())
 }
 
-pub fn part2(input: &[u8]) -> anyhow::Result<String> {
+pub fn part2(input: &[u8], params: &Params) -> anyhow::Result<Answer> {
     let sensors = parsers::parse(many0(terminated(parse_sensor, newline)), input)?;
-    find_beacon(sensors, 4000000).map(|r| r.to_string())
+    find_beacon(sensors, params.get("max_coord")?).map(Answer::from)
 }
 
 fn find_beacon(mut sensors: Vec<Sensor>, max_coord: i32) -> anyhow::Result<u64> {
@@ -143,19 +138,3 @@ impl Sensor {
         self.beacon.manhattan(self.position)
     }
 }
-
-#[test]
-fn test_example() {
-    let input = include_bytes!("../inputs/day15/example.txt");
-    let sensors = parsers::parse(many0(terminated(parse_sensor, newline)), input).unwrap();
-
-    let result = count_row(&sensors, 10).unwrap();
-
-    assert_eq!(result, 26);
-
-    let result2 = find_beacon(sensors, 20).unwrap();
-
-    assert_eq!(result2, 56000011)
-}
-
-crate::test_day!(RUN, "day15", "5607466", "12543202766584");
@@ -12,15 +12,16 @@ use rustc_hash::FxHashMap;
 
 use crate::{
     parsers::{self, newline},
-    Day,
+    search::{self, AllPairs},
+    Answer, Day,
 };
 
-pub static RUN: Day = Day { part1, part2 };
+pub static RUN: Day = Day::new(part1, part2).titled("Proboscidea Volcanium");
 
-pub fn part1(input: &[u8]) -> anyhow::Result<String> {
+pub fn part1(input: &[u8]) -> anyhow::Result<Answer> {
     let (valves, start) = compile_network(input)?;
 
-    let dist = floyd_warshall(&valves);
+    let dist = distances(&valves);
     let functioning_valves = valves.iter().take_while(|v| v.flow > 0).count();
 
     let mut perm: Vec<_> = (0..functioning_valves).collect();
@@ -28,10 +29,10 @@ pub fn part1(input: &[u8]) -> anyhow::Result<String> {
     let best = search_permutations(&mut perm, &valves, &dist, start, 30);
 
     // let best = search_dp_stack(&valves, start, 30);
-    Ok(best.to_string())
+    Ok(best.into())
 }
 
-pub fn part2(input: &[u8]) -> anyhow::Result<String> {
+pub fn part2(input: &[u8]) -> anyhow::Result<Answer> {
     let (valves, start) = compile_network(input)?;
 
     let (dp, functioning) = simple_dp(&valves, start, 26);
@@ -46,7 +47,7 @@ pub fn part2(input: &[u8]) -> anyhow::Result<String> {
         .max()
         .unwrap_or(0);
 
-    Ok(best.to_string())
+    Ok(best.into())
 }
 
 fn compile_network(input: &[u8]) -> anyhow::Result<(Vec<Valve>, usize)> {
@@ -66,7 +67,6 @@ fn compile_network(input: &[u8]) -> anyhow::Result<(Vec<Valve>, usize)> {
     let valves: Vec<_> = src_valves
         .iter()
         .map(|v| Valve {
-            id: id_to_index[v.id],
             flow: v.flow,
             neighbors: v.neighbors.iter().map(|n| id_to_index[n]).collect(),
         })
@@ -77,7 +77,7 @@ fn compile_network(input: &[u8]) -> anyhow::Result<(Vec<Valve>, usize)> {
 
 fn simple_dp(valves: &[Valve], start: usize, max_time: usize) -> (ndarray::Array2<u32>, usize) {
     let functioning_valves = valves.iter().take_while(|v| v.flow > 0).count();
-    let dist = floyd_warshall(valves);
+    let dist = distances(valves);
 
     // dp[(time])[set of valves to operate][start]
     //  = maximum relief achievable with given time starting at start
@@ -90,10 +90,10 @@ fn simple_dp(valves: &[Valve], start: usize, max_time: usize) -> (ndarray::Array
     let mut prev = dp.clone();
 
     for remaining_time in 1..max_time {
-        for pos in 0..valves.len() {
+        for (pos, valve) in valves.iter().enumerate() {
             // can we even get here? - Pruning the DP array to only contain values relevant for
             // start
-            if max_time - remaining_time < dist[(start, pos)] as usize {
+            if max_time - remaining_time < dist.cost(start, pos).unwrap_or(u32::MAX) as usize {
                 continue;
             }
 
@@ -105,14 +105,14 @@ fn simple_dp(valves: &[Valve], start: usize, max_time: usize) -> (ndarray::Array
             for (valve_set, dp_value) in dp_for_pos.indexed_iter_mut() {
                 // opening valve: position remains the same, set of valves shrinks
                 let by_opening = if valve_set & bit != 0 {
-                    prev_for_pos[valve_set & !bit] + valves[pos].flow * remaining_time as u32
+                    prev_for_pos[valve_set & !bit] + valve.flow * remaining_time as u32
                 } else {
                     0
                 };
 
                 // moving: sets of valves remains, position changes
                 let prev_for_set = prev.index_axis(ndarray::Axis(1), valve_set);
-                *dp_value = valves[pos]
+                *dp_value = valve
                     .neighbors
                     .iter()
                     .map(|idx| prev_for_set[*idx])
@@ -129,14 +129,14 @@ fn simple_dp(valves: &[Valve], start: usize, max_time: usize) -> (ndarray::Array
 fn search_permutations(
     perm: &mut [usize],
     valves: &[Valve],
-    dist: &ndarray::Array2<u32>,
+    dist: &AllPairs<u32>,
     start: usize,
     max_time: u32,
 ) -> u32 {
     let total_flow = perm.iter().map(|index| valves[*index].flow).sum::<u32>();
     // Sorting provides a minor speedup by exploring more reasonable paths first - but doesn't
     // influence correctness
-    perm.sort_unstable_by_key(|p| dist[(start, *p)]);
+    perm.sort_unstable_by_key(|p| dist.cost(start, *p).unwrap_or(u32::MAX));
 
     let mut taken = 0;
     let mut flow = 0;
@@ -161,13 +161,14 @@ fn search_permutations(
             perm.swap(taken, taken + next);
 
             let next_pos = perm[taken];
-            let steps = dist[(cur.pos as usize, next_pos)];
+            let steps = dist.cost(cur.pos as usize, next_pos).unwrap_or(u32::MAX);
 
             // assuming we'd open all remaining valves instantaneously, could we still improve the
             // solution?
             let next_time = cur.time + steps + 1;
-            let hypothetical_relief =
-                relief + (next_time - cur.time) * flow + (max_time - next_time) * total_flow;
+            let hypothetical_relief = relief
+                + (next_time - cur.time) * flow
+                + max_time.saturating_sub(next_time) * total_flow;
 
             if hypothetical_relief <= best_relief {
                 // undo
@@ -224,33 +225,14 @@ struct State {
     choice: usize,
 }
 
-/// Computes distances between each pair of vertices
-/// Curtesy of https://en.wikipedia.org/wiki/Floyd–Warshall_algorithm
-fn floyd_warshall(valves: &[Valve]) -> ndarray::Array2<u32> {
-    let mut dist = ndarray::Array2::<u32>::from_elem((valves.len(), valves.len()), u32::MAX);
-    for v in valves {
-        for n in v.neighbors.iter().copied() {
-            assert_ne!(v.id, n);
-            dist[(v.id, n)] = 1;
-        }
-        dist[(v.id, v.id)] = 0;
-    }
-
-    for k in 0..valves.len() {
-        for i in 0..valves.len() {
-            for j in 0..valves.len() {
-                let indirect = dist[(i, k)].saturating_add(dist[(k, j)]);
-                if dist[(i, j)] > indirect {
-                    dist[(i, j)] = indirect;
-                }
-            }
-        }
-    }
-
-    dist
+/// Computes distances between each pair of valves
+fn distances(valves: &[Valve]) -> AllPairs<u32> {
+    search::all_pairs(valves.len(), |&v: &usize, out: &mut Vec<_>| {
+        out.extend(valves[v].neighbors.iter().map(|&n| (n, 1)))
+    })
 }
 
-fn parse_valve(input: &[u8]) -> IResult<&[u8], SrcValve> {
+fn parse_valve(input: &[u8]) -> IResult<&[u8], SrcValve<'_>> {
     map_res(
         separated_pair(
             pair(
@@ -286,7 +268,7 @@ fn to_dot(valves: &[SrcValve]) -> String {
     for v in valves {
         writeln!(&mut out, "{} [label=\"{} : {}\"];", v.id, v.id, v.flow).unwrap();
         for n in &v.neighbors {
-            if v.id < n {
+            if v.id < *n {
                 writeln!(&mut out, "{} -- {};", v.id, n).unwrap();
             }
         }
@@ -304,9 +286,6 @@ struct SrcValve<'a> {
 
 #[derive(Debug)]
 struct Valve {
-    id: usize,
     flow: u32,
     neighbors: Vec<usize>,
 }
-
-crate::test_day!(RUN, "day16", "2330", "2675");
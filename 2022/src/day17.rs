@@ -1,12 +1,22 @@
 use std::fmt::{Display, Write};
 
-use rustc_hash::FxHashMap;
-
-use crate::Day;
-
-pub static RUN: Day = Day { part1, part2 };
-
-pub fn part1(input: &[u8]) -> anyhow::Result<String> {
+use crate::{
+    cycle,
+    params::{Param, Params},
+    Answer, Day,
+};
+
+pub static RUN: Day = Day::with_params(
+    &[
+        Param::new("part1_rocks", 2022),
+        Param::new("part2_rocks", 1000000000000),
+    ],
+    part1,
+    part2,
+)
+.titled("Pyroclastic Flow");
+
+pub fn part1(input: &[u8], params: &Params) -> anyhow::Result<Answer> {
     let jet_input: Vec<_> = input
         .iter()
         .copied()
@@ -16,14 +26,14 @@ pub fn part1(input: &[u8]) -> anyhow::Result<String> {
 
     let mut sim = Simulator::new(&SHAPES, &jet_input);
 
-    for _ in 0..2022 {
+    for _ in 0..params.get::<usize>("part1_rocks")? {
         sim.rock_fall();
     }
 
-    Ok(sim.cave.rock_height.to_string())
+    Ok(sim.cave.rock_height.into())
 }
 
-pub fn part2(input: &[u8]) -> anyhow::Result<String> {
+pub fn part2(input: &[u8], params: &Params) -> anyhow::Result<Answer> {
     let jet_input: Vec<_> = input
         .iter()
         .copied()
@@ -31,60 +41,28 @@ pub fn part2(input: &[u8]) -> anyhow::Result<String> {
         .filter_map(Result::ok)
         .collect();
 
-    let mut sim = Simulator::new(&SHAPES, &jet_input);
-
-    let mut num_rocks = 0;
-
-    let mut states: FxHashMap<(usize, usize, Vec<u8>), (usize, usize)> = FxHashMap::default();
-    let mut heights = Vec::new();
-
-    let output = loop {
-        // Detect cycles by finding a recurring state, identified by:
-        // 1. The index of the shape to spawn next
-        // 2. The index of the next jet
-        // 3. The effective portion of the stacked rocks
-        let state = (
-            sim.current_shape,
-            sim.current_jet,
-            sim.cave.relevant_top().to_owned(),
-        );
-
-        match states.entry(state) {
-            std::collections::hash_map::Entry::Occupied(e) => {
-                let (prev_height, prev_count) = e.get();
-
-                // We decompose the number of simulated cycles into:
-                // 1. An initialization part (start of the simulation until start of the cycle)
-                // 2. A certain number of repeated cycles
-                // 3. A remainder (which consists of the intial part of a cycle)
-                //
-                // |iiiii(ccccccc)*ccc
-                //    ^      ^       ^
-                //    |      |cycle  |partial cycle
-                //    |initialization
-
-                let cycle_length = num_rocks - prev_count;
-                let num_cycles = (1000000000000 - prev_count) / cycle_length;
-                let remainder = 1000000000000 - prev_count - num_cycles * cycle_length;
-
-                // The final height of the tower consists of the total height gain due to the cycles
-                let height_per_cycle = sim.cave.rock_height - prev_height;
-                // Plus the height gain due to the initialiation and the partial cycle
-                let final_height = num_cycles * height_per_cycle + heights[prev_count + remainder];
-
-                break final_height;
-            }
-            std::collections::hash_map::Entry::Vacant(e) => {
-                e.insert((sim.cave.rock_height, num_rocks));
-            }
-        }
-
-        num_rocks += 1;
-        heights.push(sim.cave.rock_height);
-        sim.rock_fall();
-    };
-
-    Ok(output.to_string())
+    let total_rocks: usize = params.get("part2_rocks")?;
+    let sim = Simulator::new(&SHAPES, &jet_input);
+
+    // Detect cycles by finding a recurring state, identified by:
+    // 1. The index of the shape to spawn next
+    // 2. The index of the next jet
+    // 3. The effective portion of the stacked rocks
+    let run = cycle::hashed(
+        sim,
+        total_rocks,
+        Simulator::rock_fall,
+        |sim| {
+            (
+                sim.current_shape,
+                sim.current_jet,
+                sim.cave.relevant_top().to_owned(),
+            )
+        },
+        |sim| sim.cave.rock_height,
+    );
+
+    Ok(run.metric_at(total_rocks).into())
 }
 
 struct Simulator<'a> {
@@ -276,11 +254,3 @@ impl TryFrom<u8> for Jet {
         }
     }
 }
-
-#[test]
-fn test_example() {
-    let input = include_bytes!("../inputs/day17/example.txt");
-    assert_eq!(part1(input).unwrap().as_str(), "3068");
-}
-
-crate::test_day!(RUN, "day17", "3179", "1567723342929");
@@ -1,5 +1,3 @@
-use std::ops::{Add, Sub};
-
 use nom::bytes::complete::tag;
 use nom::multi::many0;
 use nom::sequence::{terminated, tuple};
@@ -7,21 +5,23 @@ use nom::IResult;
 use nom::{character::complete::u32 as parse_u32, combinator::map};
 
 use crate::parsers::newline;
-use crate::{parsers, Day};
+use crate::vector::{Aabb, Vec3};
+use crate::{parsers, Answer, Day};
 
-pub static RUN: Day = Day { part1, part2 };
+pub static RUN: Day = Day::new(part1, part2).titled("Boiling Boulders");
 
-pub fn part1(input: &[u8]) -> anyhow::Result<String> {
+pub fn part1(input: &[u8]) -> anyhow::Result<Answer> {
     let droplets = parsers::parse(many0(terminated(parse_pos, newline)), input)?;
 
-    let (min, max) = if let Some(bounding_box) = aabb(&droplets) {
+    let bounding_box = if let Some(bounding_box) = Aabb::from_points(droplets.iter().copied()) {
         bounding_box
     } else {
         // No droplets, no sides
-        return Ok(0u32.to_string());
+        return Ok(0u32.into());
     };
+    let min = bounding_box.min;
 
-    let size = max - min + Vec3::new(1, 1, 1);
+    let size = bounding_box.size();
 
     let mut voxels = ndarray::Array3::<bool>::from_elem(index(size), false);
 
@@ -44,20 +44,21 @@ pub fn part1(input: &[u8]) -> anyhow::Result<String> {
         })
         .sum();
 
-    Ok(out.to_string())
+    Ok(out.into())
 }
 
-pub fn part2(input: &[u8]) -> anyhow::Result<String> {
+pub fn part2(input: &[u8]) -> anyhow::Result<Answer> {
     let droplets = parsers::parse(many0(terminated(parse_pos, newline)), input)?;
 
-    let (min, max) = if let Some(bounding_box) = aabb(&droplets) {
+    let bounding_box = if let Some(bounding_box) = Aabb::from_points(droplets.iter().copied()) {
         bounding_box
     } else {
         // No droplets, no sides
-        return Ok(0u32.to_string());
+        return Ok(0u32.into());
     };
+    let Aabb { min, max } = bounding_box;
 
-    let size = max - min + Vec3::new(1, 1, 1);
+    let size = bounding_box.size();
 
     // 0 -> unprocessed air, 1 -> droplet, 2 -> outside air
     let mut voxels = ndarray::Array3::from_elem(index(size), Voxel::Air);
@@ -125,18 +126,7 @@ pub fn part2(input: &[u8]) -> anyhow::Result<String> {
         }
     }
 
-    Ok(out.to_string())
-}
-
-fn aabb<T: Ord + Copy>(points: &[Vec3<T>]) -> Option<(Vec3<T>, Vec3<T>)> {
-    points.split_first().map(|(first, rest)| {
-        rest.iter().fold((*first, *first), |(min, max), droplet| {
-            (
-                min.zip_with(*droplet, Ord::min),
-                max.zip_with(*droplet, Ord::max),
-            )
-        })
-    })
+    Ok(out.into())
 }
 
 fn index(droplet: Vec3<i32>) -> (usize, usize, usize) {
@@ -150,71 +140,6 @@ enum Voxel {
     OutsideAir,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct Vec3<T> {
-    pub x: T,
-    pub y: T,
-    pub z: T,
-}
-
-impl<T> Vec3<T> {
-    pub const fn new(x: T, y: T, z: T) -> Self {
-        Self { x, y, z }
-    }
-
-    pub fn zip_with<S, R>(self, other: Vec3<S>, mut f: impl FnMut(T, S) -> R) -> Vec3<R> {
-        Vec3 {
-            x: f(self.x, other.x),
-            y: f(self.y, other.y),
-            z: f(self.z, other.z),
-        }
-    }
-
-    pub fn all(self, mut f: impl FnMut(&T) -> bool) -> bool {
-        f(&self.x) && f(&self.y) && f(&self.z)
-    }
-
-    pub fn map<R>(self, mut f: impl FnMut(T) -> R) -> Vec3<R> {
-        Vec3 {
-            x: f(self.x),
-            y: f(self.y),
-            z: f(self.z),
-        }
-    }
-}
-
-impl Vec3<bool> {
-    pub fn and(self) -> bool {
-        self.x && self.y && self.z
-    }
-}
-
-impl<T: Default> Default for Vec3<T> {
-    fn default() -> Self {
-        Self {
-            x: Default::default(),
-            y: Default::default(),
-            z: Default::default(),
-        }
-    }
-}
-
-impl<T: Add<T>> Add<Vec3<T>> for Vec3<T> {
-    type Output = Vec3<T::Output>;
-
-    fn add(self, rhs: Vec3<T>) -> Self::Output {
-        self.zip_with(rhs, Add::add)
-    }
-}
-
-impl<T: Sub<T>> Sub<Vec3<T>> for Vec3<T> {
-    type Output = Vec3<T::Output>;
-
-    fn sub(self, rhs: Vec3<T>) -> Self::Output {
-        self.zip_with(rhs, Sub::sub)
-    }
-}
-
 static SIDES: [Vec3<i32>; 6] = [
     Vec3::new(1, 0, 0),
     Vec3::new(-1, 0, 0),
@@ -230,5 +155,3 @@ fn parse_pos(input: &[u8]) -> IResult<&[u8], Vec3<i32>> {
         |(x, _, y, _, z)| Vec3 { x, y, z }.map(|elem| elem as i32),
     )(input)
 }
-
-crate::test_day!(RUN, "day18", "3494", "2062");
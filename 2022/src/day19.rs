@@ -14,37 +14,49 @@ use nom::{
 use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::{
+    params::{Param, Params},
     parsers::{self, newline},
-    Day,
+    Answer, Day,
 };
 
-pub static RUN: Day = Day { part1, part2 };
-
-pub fn part1(input: &[u8]) -> anyhow::Result<String> {
+pub static RUN: Day = Day::with_params(
+    &[
+        Param::new("part1_minutes", 24),
+        Param::new("part2_minutes", 32),
+        Param::new("part2_blueprints", 3),
+    ],
+    part1,
+    part2,
+)
+.titled("Not Enough Minerals");
+
+pub fn part1(input: &[u8], params: &Params) -> anyhow::Result<Answer> {
     let blueprints = parsers::parse(many0(terminated(parse_blueprint, newline)), input)?;
+    let minutes = params.get("part1_minutes")?;
 
     let mut result = 0;
 
     for blueprint in blueprints {
-        let res = search_iter(&blueprint, 24, [0; 4], [1, 0, 0, 0]);
+        let res = search_iter(&blueprint, minutes, [0; 4], [1, 0, 0, 0]);
         result += (blueprint.id as u64) * (res as u64);
     }
 
-    Ok(result.to_string())
+    Ok(result.into())
 }
 
-pub fn part2(input: &[u8]) -> anyhow::Result<String> {
+pub fn part2(input: &[u8], params: &Params) -> anyhow::Result<Answer> {
     let mut blueprints = parsers::parse(many0(terminated(parse_blueprint, newline)), input)?;
-    blueprints.truncate(3);
+    blueprints.truncate(params.get("part2_blueprints")?);
+    let minutes = params.get("part2_minutes")?;
 
     let mut result = 1;
 
     for blueprint in blueprints {
-        let res = search_iter(&blueprint, 32, [0; 4], [1, 0, 0, 0]);
+        let res = search_iter(&blueprint, minutes, [0; 4], [1, 0, 0, 0]);
         result *= (res as u64);
     }
 
-    Ok(result.to_string())
+    Ok(result.into())
 }
 
 fn print_trace(blueprint: &Blueprint, hist: &[Option<Res>]) {
@@ -170,14 +182,13 @@ fn try_build_robot(mut resources: [u8; 4], cost: [u8; 4]) -> Option<[u8; 4]> {
 }
 
 fn extrapolate(remaining_time: u8, resources: [u8; 4], robots: [u8; 4]) -> u8 {
-    // how many geodes can we still crack in the best case
-    let mut geodes = resources[Res::Geode.index()];
-    let mut geode_bots = robots[Res::Geode.index()];
-    for _ in 0..remaining_time {
-        geodes += geode_bots;
-        geode_bots += 1;
-    }
-    geodes
+    // how many geodes can we still crack in the best case, i.e. when building another geode robot
+    // in every remaining minute
+    let geodes = resources[Res::Geode.index()] as u32;
+    let geode_bots = robots[Res::Geode.index()] as u32;
+    let time = remaining_time as u32;
+    let best = geodes + geode_bots * time + time * time.saturating_sub(1) / 2;
+    best.min(u8::MAX as u32) as u8
 }
 
 //type State = (u8, [u8; 4], [u8; 4]); // (Time, Resources, Robots)
@@ -254,5 +265,3 @@ struct Blueprint {
     id: usize,
     cost: [[u8; 4]; 4],
 }
-
-crate::test_day!(RUN, "day19", "1487", "13440");
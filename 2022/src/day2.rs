@@ -7,20 +7,20 @@ use nom::{
 
 use crate::{
     parsers::{self, byte_range},
-    Day,
+    Answer, Day,
 };
 
-pub static RUN: Day = Day { part1, part2 };
+pub static RUN: Day = Day::new(part1, part2).titled("Rock Paper Scissors");
 
-pub fn part1(input: &[u8]) -> anyhow::Result<String> {
+pub fn part1(input: &[u8]) -> anyhow::Result<Answer> {
     shared(input, &SCORE_PART1)
 }
 
-pub fn part2(input: &[u8]) -> anyhow::Result<String> {
+pub fn part2(input: &[u8]) -> anyhow::Result<Answer> {
     shared(input, &SCORE_PART2)
 }
 
-pub fn shared(input: &[u8], table: &[[u32; 3]; 3]) -> anyhow::Result<String> {
+pub fn shared(input: &[u8], table: &[[u32; 3]; 3]) -> anyhow::Result<Answer> {
     let total_score = parsers::parse(
         fold_many0(
             terminated(
@@ -32,7 +32,7 @@ pub fn shared(input: &[u8], table: &[[u32; 3]; 3]) -> anyhow::Result<String> {
         ),
         input,
     )?;
-    Ok(total_score.to_string())
+    Ok(total_score.into())
 }
 
 // A, X -> Rock
@@ -58,5 +58,3 @@ const SCORE_PART2: [[u32; 3]; 3] = [
     [1, 5, 9], // B
     [2, 6, 7], // C
 ];
-
-crate::test_day!(RUN, "day2", "11666", "12767");
@@ -7,12 +7,12 @@ use nom::{multi::many0, sequence::terminated};
 
 use crate::{
     parsers::{self, newline},
-    Day,
+    Answer, Day,
 };
 
-pub static RUN: Day = Day { part1, part2 };
+pub static RUN: Day = Day::new(part1, part2).titled("Grove Positioning System");
 
-pub fn part1(input: &[u8]) -> anyhow::Result<String> {
+pub fn part1(input: &[u8]) -> anyhow::Result<Answer> {
     let nums = parsers::parse(
         many0(terminated(nom::character::complete::i64, newline)),
         input,
@@ -49,10 +49,10 @@ pub fn part1(input: &[u8]) -> anyhow::Result<String> {
         .map(|pos| seq.nums[seq.pos_to_num[pos]])
         .sum::<i64>();
 
-    Ok(ret.to_string())
+    Ok(ret.into())
 }
 
-pub fn part2(input: &[u8]) -> anyhow::Result<String> {
+pub fn part2(input: &[u8]) -> anyhow::Result<Answer> {
     let mut nums = parsers::parse(
         many0(terminated(nom::character::complete::i64, newline)),
         input,
@@ -96,7 +96,7 @@ pub fn part2(input: &[u8]) -> anyhow::Result<String> {
         .map(|pos| seq.nums[seq.pos_to_num[pos]])
         .sum::<i64>();
 
-    Ok(ret.to_string())
+    Ok(ret.into())
 }
 
 struct Seq {
@@ -149,5 +149,3 @@ impl Seq {
         self.num_to_pos[num] = right_pos;
     }
 }
-
-crate::test_day!(RUN, "day20", "23321", "1428396909280");
@@ -15,21 +15,21 @@ use rustc_hash::FxHashMap;
 
 use crate::{
     parsers::{self, newline},
-    Day,
+    Answer, Day,
 };
 
-pub static RUN: Day = Day { part1, part2 };
+pub static RUN: Day = Day::new(part1, part2).titled("Monkey Math");
 
-pub fn part1(input: &[u8]) -> anyhow::Result<String> {
+pub fn part1(input: &[u8]) -> anyhow::Result<Answer> {
     let monkeys = parsers::parse(many1(terminated(parse_monkey, newline)), input)?;
 
     let mut monkey_lookup: FxHashMap<MonkeyId, _> =
         monkeys.into_iter().map(|m| (m.id, m.expr)).collect();
 
-    eval(&monkey_lookup, MonkeyId::ROOT).map(|ret| ret.to_string())
+    eval(&monkey_lookup, MonkeyId::ROOT).map(Answer::from)
 }
 
-pub fn part2(input: &[u8]) -> anyhow::Result<String> {
+pub fn part2(input: &[u8]) -> anyhow::Result<Answer> {
     let monkeys = parsers::parse(many1(terminated(parse_monkey, newline)), input)?;
     let mut monkey_lookup: FxHashMap<MonkeyId, _> =
         monkeys.into_iter().map(|m| (m.id, m.expr)).collect();
@@ -124,7 +124,7 @@ pub fn part2(input: &[u8]) -> anyhow::Result<String> {
         }
     }
 
-    Ok(no_human.to_string())
+    Ok(no_human.into())
 }
 
 fn eval<'a>(
@@ -153,18 +153,18 @@ fn eval_op(op: Op, lhs: i64, rhs: i64) -> i64 {
     }
 }
 
-fn parse_monkey(input: &[u8]) -> IResult<&[u8], Monkey> {
+fn parse_monkey(input: &[u8]) -> IResult<&[u8], Monkey<'_>> {
     map(
         separated_pair(parse_id, tag(": "), parse_expr),
         |(id, expr)| Monkey { id, expr },
     )(input)
 }
 
-fn parse_id(input: &[u8]) -> IResult<&[u8], MonkeyId> {
-    map(take_while1(|ch| (b'a'..=b'z').contains(&ch)), MonkeyId)(input)
+fn parse_id(input: &[u8]) -> IResult<&[u8], MonkeyId<'_>> {
+    map(take_while1(|ch: u8| ch.is_ascii_lowercase()), MonkeyId)(input)
 }
 
-fn parse_expr(input: &[u8]) -> IResult<&[u8], Expr> {
+fn parse_expr(input: &[u8]) -> IResult<&[u8], Expr<'_>> {
     alt((
         map(nom::character::complete::u32, |x| Expr::Const(x as i64)),
         map(
@@ -217,5 +217,3 @@ enum Op {
     Mul,
     Div,
 }
-
-crate::test_day!(RUN, "day21", "83056452926300", "3469704905529");
@@ -1,5 +1,3 @@
-use std::ops::{Add, Sub};
-
 use anyhow::Context;
 use nom::{
     branch::alt,
@@ -10,13 +8,19 @@ use nom::{
 };
 use rustc_hash::FxHashMap;
 
-use crate::{parsers, Day};
+use crate::{
+    params::{Param, Params},
+    parsers,
+    vector::Vec3,
+    Answer, Day,
+};
 
-pub static RUN: Day = Day { part1, part2 };
+pub static RUN: Day =
+    Day::with_params(&[Param::new("face_width", 50)], part1, part2).titled("Monkey Map");
 
 // TODO: investigate more efficient representation of tilemap than a hash map
 
-pub fn part1(input: &[u8]) -> anyhow::Result<String> {
+pub fn part1(input: &[u8], _params: &Params) -> anyhow::Result<Answer> {
     let (tiles, start, instructions) = parse_input(input)?;
 
     let mut heading = Heading::Right;
@@ -57,7 +61,7 @@ pub fn part1(input: &[u8]) -> anyhow::Result<String> {
     }
 
     let password = 1000 * position.1 + 4 * position.0 + (heading as i32);
-    Ok(password.to_string())
+    Ok(password.into())
 }
 
 fn heading_delta(heading: Heading) -> (i32, i32) {
@@ -69,14 +73,14 @@ fn heading_delta(heading: Heading) -> (i32, i32) {
     }
 }
 
+#[allow(clippy::type_complexity)]
 fn parse_input(
     input: &[u8],
 ) -> anyhow::Result<(FxHashMap<(i32, i32), Tile>, (i32, i32), Vec<Instr>)> {
     let mut tiles: FxHashMap<(i32, i32), Tile> = FxHashMap::default();
     let mut lines = input.split(|ch| *ch == b'\n');
-    let mut row = 1;
     let mut start = None;
-    for line in lines.by_ref() {
+    for (row, line) in (1..).zip(lines.by_ref()) {
         if line.is_empty() {
             // separator between map and instructions
             break;
@@ -89,8 +93,6 @@ fn parse_input(
                 }
             }
         }
-
-        row += 1;
     }
     let instructions_string = lines.next().context("missing instructions")?;
     let instructions = parsers::parse(many0(parse_instruction), instructions_string)?;
@@ -98,20 +100,14 @@ fn parse_input(
     Ok((tiles, start, instructions))
 }
 
-pub fn part2(input: &[u8]) -> anyhow::Result<String> {
+pub fn part2(input: &[u8], params: &Params) -> anyhow::Result<Answer> {
     let (tiles, start, instructions) = parse_input(input)?;
+    let width: i32 = params.get("face_width")?;
 
     let (max_col, max_row) = tiles
         .keys()
         .fold((0, 0), |(mc, mr), (c, r)| (mc.max(*c), mr.max(*r)));
 
-    // detect example
-    let width = if max_col % 50 == 0 && max_row % 50 == 0 {
-        50
-    } else {
-        4
-    };
-
     // find faces
     let mut faces = FxHashMap::default();
     let mut faces_by_id = Vec::new();
@@ -288,7 +284,7 @@ pub fn part2(input: &[u8]) -> anyhow::Result<String> {
     }
 
     let password = 1000 * position.1 + 4 * position.0 + (heading as i32);
-    Ok(password.to_string())
+    Ok(password.into())
 }
 
 fn parse_instruction(input: &[u8]) -> IResult<&[u8], Instr> {
@@ -300,10 +296,6 @@ fn parse_instruction(input: &[u8]) -> IResult<&[u8], Instr> {
     ))(input)
 }
 
-/// Edge transitions of each cube face
-#[derive(Clone, PartialEq, Eq, Debug)]
-struct FaceTransition([Option<(usize, Heading)>; 4]);
-
 /// A literal cube with data attached to each of its faces. The front face (Z coordinate -1) has a
 /// special meaning.
 ///
@@ -321,9 +313,12 @@ struct Cube {
 struct CubeFace {
     center: Vec3<i32>,
     face_id: Option<usize>,
-    corners: [(Vec3<i32>, Option<(i32, i32)>); 4],
+    corners: [Corner; 4],
 }
 
+/// A corner of a cube face, together with the map coordinates it corresponds to.
+type Corner = (Vec3<i32>, Option<(i32, i32)>);
+
 impl CubeFace {
     fn new(center: Vec3<i32>, dx: Vec3<i32>, dy: Vec3<i32>) -> Self {
         Self {
@@ -500,71 +495,6 @@ impl TryFrom<u8> for Tile {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct Vec3<T> {
-    pub x: T,
-    pub y: T,
-    pub z: T,
-}
-
-impl<T> Vec3<T> {
-    pub const fn new(x: T, y: T, z: T) -> Self {
-        Self { x, y, z }
-    }
-
-    pub fn zip_with<S, R>(self, other: Vec3<S>, mut f: impl FnMut(T, S) -> R) -> Vec3<R> {
-        Vec3 {
-            x: f(self.x, other.x),
-            y: f(self.y, other.y),
-            z: f(self.z, other.z),
-        }
-    }
-
-    pub fn all(self, mut f: impl FnMut(&T) -> bool) -> bool {
-        f(&self.x) && f(&self.y) && f(&self.z)
-    }
-
-    pub fn map<R>(self, mut f: impl FnMut(T) -> R) -> Vec3<R> {
-        Vec3 {
-            x: f(self.x),
-            y: f(self.y),
-            z: f(self.z),
-        }
-    }
-}
-
-impl Vec3<bool> {
-    pub fn and(self) -> bool {
-        self.x && self.y && self.z
-    }
-}
-
-impl<T: Default> Default for Vec3<T> {
-    fn default() -> Self {
-        Self {
-            x: Default::default(),
-            y: Default::default(),
-            z: Default::default(),
-        }
-    }
-}
-
-impl<T: Add<T>> Add<Vec3<T>> for Vec3<T> {
-    type Output = Vec3<T::Output>;
-
-    fn add(self, rhs: Vec3<T>) -> Self::Output {
-        self.zip_with(rhs, Add::add)
-    }
-}
-
-impl<T: Sub<T>> Sub<Vec3<T>> for Vec3<T> {
-    type Output = Vec3<T::Output>;
-
-    fn sub(self, rhs: Vec3<T>) -> Self::Output {
-        self.zip_with(rhs, Sub::sub)
-    }
-}
-
 fn rot_up(vec: Vec3<i32>) -> Vec3<i32> {
     Vec3::new(vec.x, vec.z, -vec.y)
 }
@@ -596,5 +526,3 @@ fn div_floor(a: i32, b: i32) -> i32 {
         a / b
     }
 }
-
-crate::test_day!(RUN, "day22", "93226", "37415");
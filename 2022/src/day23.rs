@@ -1,18 +1,18 @@
-use std::{
-    collections::VecDeque,
-    ops::{Add, Sub},
-};
+use std::collections::VecDeque;
 
 use anyhow::Context;
 use rustc_hash::{FxHashMap, FxHashSet};
 
-use crate::Day;
+use crate::{
+    vector::{Aabb, Vec2},
+    Answer, Day,
+};
 
-pub static RUN: Day = Day { part1, part2 };
+pub static RUN: Day = Day::new(part1, part2).titled("Unstable Diffusion");
 
 // TODO: optimize - hashmaps are probably slow
 
-pub fn part1(input: &[u8]) -> anyhow::Result<String> {
+pub fn part1(input: &[u8]) -> anyhow::Result<Answer> {
     let mut elves_vec = parse_input(input);
 
     let mut dirs: VecDeque<_> = vec![Dir::North, Dir::South, Dir::West, Dir::East].into();
@@ -90,22 +90,14 @@ pub fn part1(input: &[u8]) -> anyhow::Result<String> {
     }
 
     // compute AABB
-    let (min, max) = aabb(elves.iter()).context("no elves, no aabb")?;
+    let bounds = Aabb::from_points(elves.iter().copied()).context("no elves, no aabb")?;
 
-    let free = (max.x - min.x + 1) * (max.y - min.y + 1) - elves.len() as i32;
-
-    Ok(free.to_string())
-}
+    let free = bounds.volume() - elves.len() as i32;
 
-fn aabb<'a>(mut elves_iter: impl Iterator<Item = &'a Vec2<i32>>) -> Option<(Vec2<i32>, Vec2<i32>)> {
-    let first = elves_iter.next().copied()?;
-    let (min, max) = elves_iter.fold((first, first), |(min, max), elve| {
-        (min.zip_with(*elve, Ord::min), max.zip_with(*elve, Ord::max))
-    });
-    Some((min, max))
+    Ok(free.into())
 }
 
-pub fn part2(input: &[u8]) -> anyhow::Result<String> {
+pub fn part2(input: &[u8]) -> anyhow::Result<Answer> {
     let mut elves_vec = parse_input(input);
 
     let mut dirs: VecDeque<_> = vec![Dir::North, Dir::South, Dir::West, Dir::East].into();
@@ -186,7 +178,7 @@ pub fn part2(input: &[u8]) -> anyhow::Result<String> {
         dirs.rotate_left(1);
     }
 
-    Ok(round.to_string())
+    Ok(round.into())
 }
 
 fn parse_input(input: &[u8]) -> Vec<Vec2<i32>> {
@@ -203,7 +195,7 @@ fn parse_input(input: &[u8]) -> Vec<Vec2<i32>> {
 
 #[allow(unused)]
 fn debug_print(elves: &FxHashSet<Vec2<i32>>) {
-    let (min, max) = aabb(elves.iter()).unwrap();
+    let Aabb { min, max } = Aabb::from_points(elves.iter().copied()).unwrap();
     for y in min.y..=max.y {
         for x in min.x..=max.x {
             print!(
@@ -241,56 +233,3 @@ const NORTH_NEIGHBORS: [Vec2<i32>; 3] = [Vec2::new(-1, -1), Vec2::new(0, -1), Ve
 const EAST_NEIGHBORS: [Vec2<i32>; 3] = [Vec2::new(1, -1), Vec2::new(1, 0), Vec2::new(1, 1)];
 const WEST_NEIGHBORS: [Vec2<i32>; 3] = [Vec2::new(-1, -1), Vec2::new(-1, 0), Vec2::new(-1, 1)];
 const SOUTH_NEIGHBORS: [Vec2<i32>; 3] = [Vec2::new(1, 1), Vec2::new(0, 1), Vec2::new(-1, 1)];
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct Vec2<T> {
-    pub x: T,
-    pub y: T,
-}
-
-impl<T> Vec2<T> {
-    pub const fn new(x: T, y: T) -> Self {
-        Self { x, y }
-    }
-
-    pub fn zip_with<S, R>(self, other: Vec2<S>, mut f: impl FnMut(T, S) -> R) -> Vec2<R> {
-        Vec2 {
-            x: f(self.x, other.x),
-            y: f(self.y, other.y),
-        }
-    }
-
-    pub fn map<R>(self, mut f: impl FnMut(T) -> R) -> Vec2<R> {
-        Vec2 {
-            x: f(self.x),
-            y: f(self.y),
-        }
-    }
-}
-
-impl<T: Default> Default for Vec2<T> {
-    fn default() -> Self {
-        Self {
-            x: Default::default(),
-            y: Default::default(),
-        }
-    }
-}
-
-impl<T: Add<T>> Add<Vec2<T>> for Vec2<T> {
-    type Output = Vec2<T::Output>;
-
-    fn add(self, rhs: Vec2<T>) -> Self::Output {
-        self.zip_with(rhs, Add::add)
-    }
-}
-
-impl<T: Sub<T>> Sub<Vec2<T>> for Vec2<T> {
-    type Output = Vec2<T::Output>;
-
-    fn sub(self, rhs: Vec2<T>) -> Self::Output {
-        self.zip_with(rhs, Sub::sub)
-    }
-}
-
-crate::test_day!(RUN, "day23", "4249", "980");
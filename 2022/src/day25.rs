@@ -7,12 +7,14 @@ use nom::{
 
 use crate::{
     parsers::{self, newline},
-    Day,
+    Answer, Day,
 };
 
-pub static RUN: Day = Day { part1, part2 };
+pub static RUN: Day = Day::new(part1, part2)
+    .titled("Full of Hot Air")
+    .without_bench();
 
-pub fn part1(input: &[u8]) -> anyhow::Result<String> {
+pub fn part1(input: &[u8]) -> anyhow::Result<Answer> {
     let fuel = parsers::parse(
         fold_many0(
             terminated(parse_snafu_to_decimal, newline),
@@ -24,7 +26,7 @@ pub fn part1(input: &[u8]) -> anyhow::Result<String> {
 
     let result = decimal_to_snafu(fuel);
 
-    Ok(result)
+    Ok(result.into())
 }
 
 fn decimal_to_snafu(fuel: i64) -> String {
@@ -55,8 +57,8 @@ fn decimal_to_snafu(fuel: i64) -> String {
     String::from_utf8(out).unwrap()
 }
 
-pub fn part2(input: &[u8]) -> anyhow::Result<String> {
-    Ok("n/a".to_string())
+pub fn part2(input: &[u8]) -> anyhow::Result<Answer> {
+    Ok(Answer::NotApplicable)
 }
 
 fn parse_snafu_to_decimal(input: &[u8]) -> IResult<&[u8], i64> {
@@ -95,5 +97,3 @@ fn digit_snafu(digit: i64) -> char {
         _ => panic!("invalid snafu digit"),
     }
 }
-
-crate::test_day!(RUN, "day25", "2-2--02=1---1200=0-1", "n/a");
@@ -8,12 +8,12 @@ use nom::{
 
 use crate::{
     parsers::{self, newline},
-    Day,
+    Answer, Day,
 };
 
-pub static RUN: Day = Day { part1, part2 };
+pub static RUN: Day = Day::new(part1, part2).titled("Rucksack Reorganization");
 
-pub fn part1(input: &[u8]) -> anyhow::Result<String> {
+pub fn part1(input: &[u8]) -> anyhow::Result<Answer> {
     let sum = parsers::parse(
         fold_many0(
             terminated(parse_rucksack_halves, newline),
@@ -22,10 +22,10 @@ pub fn part1(input: &[u8]) -> anyhow::Result<String> {
         ),
         input,
     )?;
-    Ok(sum.to_string())
+    Ok(sum.into())
 }
 
-pub fn part2(input: &[u8]) -> anyhow::Result<String> {
+pub fn part2(input: &[u8]) -> anyhow::Result<Answer> {
     let sum = parsers::parse(
         fold_many0(
             tuple((
@@ -38,7 +38,7 @@ pub fn part2(input: &[u8]) -> anyhow::Result<String> {
         ),
         input,
     )?;
-    Ok(sum.to_string())
+    Ok(sum.into())
 }
 
 fn priority(item: u8) -> u8 {
@@ -70,5 +70,3 @@ fn items_to_bitset(items: &[u8]) -> u64 {
         .map(priority)
         .fold(0, |set, index| set | (1u64 << index))
 }
-
-crate::test_day!(RUN, "day3", "8105", "2363");
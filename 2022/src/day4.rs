@@ -1,8 +1,3 @@
-#![allow(unused)]
-
-use std::ops::RangeInclusive;
-
-use anyhow::bail;
 use nom::{
     bytes::complete::tag,
     combinator::map,
@@ -12,29 +7,30 @@ use nom::{
 };
 
 use crate::{
+    interval::Interval,
     parsers::{self, newline},
-    Day,
+    Answer, Day,
 };
 
-pub static RUN: Day = Day { part1, part2 };
+pub static RUN: Day = Day::new(part1, part2).titled("Camp Cleanup");
 
-pub fn part1(input: &[u8]) -> anyhow::Result<String> {
+pub fn part1(input: &[u8]) -> anyhow::Result<Answer> {
     let fully_overlapping = parsers::parse(
-        parse_ranges_count_if(|r1, r2| fully_contains(r1, r2) || fully_contains(r2, r1)),
+        parse_ranges_count_if(|r1, r2| r1.contains_interval(r2) || r2.contains_interval(r1)),
         input,
     )?;
 
-    Ok(fully_overlapping.to_string())
+    Ok(fully_overlapping.into())
 }
 
-pub fn part2(input: &[u8]) -> anyhow::Result<String> {
-    let overlapping = parsers::parse(parse_ranges_count_if(|r1, r2| !disjoint(r1, r2)), input)?;
+pub fn part2(input: &[u8]) -> anyhow::Result<Answer> {
+    let overlapping = parsers::parse(parse_ranges_count_if(|r1, r2| r1.overlaps(r2)), input)?;
 
-    Ok(overlapping.to_string())
+    Ok(overlapping.into())
 }
 
 fn parse_ranges_count_if(
-    cond: impl Fn(&RangeInclusive<u32>, &RangeInclusive<u32>) -> bool,
+    cond: impl Fn(&Interval<u32>, &Interval<u32>) -> bool,
 ) -> impl Fn(&[u8]) -> IResult<&[u8], u32> {
     move |input| {
         fold_many0(
@@ -45,19 +41,9 @@ fn parse_ranges_count_if(
     }
 }
 
-fn parse_range(input: &[u8]) -> IResult<&[u8], RangeInclusive<u32>> {
+fn parse_range(input: &[u8]) -> IResult<&[u8], Interval<u32>> {
     map(
         separated_pair(parsers::u32, tag("-"), parsers::u32),
-        |(from, to)| from..=to,
+        |(from, to)| Interval::inclusive(from, to),
     )(input)
 }
-
-fn fully_contains(outer: &RangeInclusive<u32>, inner: &RangeInclusive<u32>) -> bool {
-    outer.start() <= inner.start() && outer.end() >= inner.end()
-}
-
-fn disjoint(r1: &RangeInclusive<u32>, r2: &RangeInclusive<u32>) -> bool {
-    r1.start() > r2.end() || r1.end() < r2.start()
-}
-
-crate::test_day!(RUN, "day4", "507", "897");
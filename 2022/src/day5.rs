@@ -12,12 +12,12 @@ use nom::{
 
 use crate::{
     parsers::{self, asciichar},
-    Day,
+    Answer, Day,
 };
 
-pub static RUN: Day = Day { part1, part2 };
+pub static RUN: Day = Day::new(part1, part2).titled("Supply Stacks");
 
-pub fn part1(input: &[u8]) -> anyhow::Result<String> {
+pub fn part1(input: &[u8]) -> anyhow::Result<Answer> {
     let (mut stacks, moves) = parsers::parse(parse_input, input)?;
 
     for mov in moves {
@@ -36,9 +36,10 @@ pub fn part1(input: &[u8]) -> anyhow::Result<String> {
                 .ok_or_else(|| anyhow::anyhow!("invalid outcome")))
         })
         .collect::<anyhow::Result<String>>()
+        .map(Answer::from)
 }
 
-pub fn part2(input: &[u8]) -> anyhow::Result<String> {
+pub fn part2(input: &[u8]) -> anyhow::Result<Answer> {
     let (mut stacks, moves) = parsers::parse(parse_input, input)?;
 
     for mov in moves {
@@ -59,6 +60,7 @@ pub fn part2(input: &[u8]) -> anyhow::Result<String> {
                 .ok_or_else(|| anyhow::anyhow!("invalid outcome")))
         })
         .collect::<anyhow::Result<String>>()
+        .map(Answer::from)
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -68,6 +70,7 @@ struct Move {
     to: u32,
 }
 
+#[allow(clippy::type_complexity)]
 fn parse_input(input: &[u8]) -> IResult<&[u8], (Vec<Vec<u8>>, Vec<Move>)> {
     separated_pair(parse_stacks, parsers::newline, many0(parse_move))(input)
 }
@@ -79,7 +82,7 @@ fn parse_stacks(input: &[u8]) -> IResult<&[u8], Vec<Vec<u8>>> {
         |mut rows, row| {
             if row
                 .iter()
-                .all(|ch| (b'A'..=b'Z').contains(ch) || b" []".contains(ch))
+                .all(|ch: &u8| ch.is_ascii_uppercase() || b" []".contains(ch))
             {
                 rows.push(row)
             }
@@ -170,5 +173,3 @@ fn test_parse_stack() {
         ))
     )
 }
-
-crate::test_day!(RUN, "day5", "RFFFWBPNS", "CQQBBJFCS");
@@ -8,30 +8,30 @@ use nom::{
     sequence::{pair, terminated},
 };
 
-use crate::{parsers, Day};
+use crate::{parsers, Answer, Day};
 
-pub static RUN: Day = Day { part1, part2 };
+pub static RUN: Day = Day::new(part1, part2).titled("Tuning Trouble");
 
-pub fn part1(input: &[u8]) -> anyhow::Result<String> {
+pub fn part1(input: &[u8]) -> anyhow::Result<Answer> {
     shared::<4>(input)
 }
 
-pub fn part2(input: &[u8]) -> anyhow::Result<String> {
+pub fn part2(input: &[u8]) -> anyhow::Result<Answer> {
     shared::<14>(input)
 }
 
-fn shared<const N: usize>(input: &[u8]) -> anyhow::Result<String> {
+fn shared<const N: usize>(input: &[u8]) -> anyhow::Result<Answer> {
     let (init, rest) = parsers::parse(
         terminated(
             pair(
                 map_opt(take(N), |bytes: &[u8]| {
-                    if bytes.iter().all(|ch| (b'a'..=b'z').contains(ch)) {
+                    if bytes.iter().all(|ch: &u8| ch.is_ascii_lowercase()) {
                         Some(bytes)
                     } else {
                         None
                     }
                 }),
-                take_while(|ch| (b'a'..=b'z').contains(&ch)),
+                take_while(|ch: u8| ch.is_ascii_lowercase()),
             ),
             opt(parsers::newline),
         ),
@@ -80,31 +80,29 @@ fn shared<const N: usize>(input: &[u8]) -> anyhow::Result<String> {
         rest_pos += 1;
     }
 
-    Ok((rest_pos + N).to_string())
+    Ok((rest_pos + N).into())
 }
 
 #[test]
 fn test_examples() {
     assert_eq!(
         part1(b"mjqjpqmgbljsphdztnvjfqwrcgsmlb").unwrap(),
-        "7".to_string()
+        Answer::Integer(7)
     );
     assert_eq!(
         part1(b"bvwbjplbgvbhsrlpgdmjqwftvncz").unwrap(),
-        "5".to_string()
+        Answer::Integer(5)
     );
     assert_eq!(
         part1(b"nppdvjthqldpwncqszvftbrmjlhg").unwrap(),
-        "6".to_string()
+        Answer::Integer(6)
     );
     assert_eq!(
         part1(b"nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg").unwrap(),
-        "10".to_string()
+        Answer::Integer(10)
     );
     assert_eq!(
         part1(b"zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw").unwrap(),
-        "11".to_string()
+        Answer::Integer(11)
     );
 }
-
-crate::test_day!(RUN, "day6", "1625", "2250");
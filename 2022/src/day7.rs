@@ -10,11 +10,11 @@ use nom::{
     IResult,
 };
 
-use crate::{parsers, Day};
+use crate::{parsers, Answer, Day};
 
-pub static RUN: Day = Day { part1, part2 };
+pub static RUN: Day = Day::new(part1, part2).titled("No Space Left On Device");
 
-pub fn part1(input: &[u8]) -> anyhow::Result<String> {
+pub fn part1(input: &[u8]) -> anyhow::Result<Answer> {
     let fs = parsers::parse(parse_tree, input)?;
     let dirsizes = compute_dir_size(&fs);
 
@@ -24,10 +24,10 @@ pub fn part1(input: &[u8]) -> anyhow::Result<String> {
         .filter(|size| *size <= 100000)
         .sum::<u64>();
 
-    Ok(result.to_string())
+    Ok(result.into())
 }
 
-pub fn part2(input: &[u8]) -> anyhow::Result<String> {
+pub fn part2(input: &[u8]) -> anyhow::Result<Answer> {
     let fs = parsers::parse(parse_tree, input)?;
     let dirsizes = compute_dir_size(&fs);
 
@@ -43,7 +43,7 @@ pub fn part2(input: &[u8]) -> anyhow::Result<String> {
         .min_by_key(|size| *size)
         .ok_or_else(|| anyhow!("no deletion candidate found"))?;
 
-    Ok(size.to_string())
+    Ok(size.into())
 }
 
 fn compute_dir_size(fs: &Fs) -> Vec<u64> {
@@ -62,7 +62,7 @@ fn compute_dir_size(fs: &Fs) -> Vec<u64> {
     dirsizes
 }
 
-fn parse_tree(input: &[u8]) -> IResult<&[u8], Fs> {
+fn parse_tree(input: &[u8]) -> IResult<&[u8], Fs<'_>> {
     let mut fs = Fs::new();
     let mut walker = Walker::new(&mut fs);
     let (rest, _) = fold_many0(
@@ -89,7 +89,7 @@ fn parse_tree(input: &[u8]) -> IResult<&[u8], Fs> {
     Ok((rest, fs))
 }
 
-fn parse_cmd(input: &[u8]) -> IResult<&[u8], Command> {
+fn parse_cmd(input: &[u8]) -> IResult<&[u8], Command<'_>> {
     terminated(
         alt((
             map(tag("ls"), |_| Command::Ls),
@@ -103,7 +103,7 @@ fn parse_cmd(input: &[u8]) -> IResult<&[u8], Command> {
     )(input)
 }
 
-fn parse_ls(input: &[u8]) -> IResult<&[u8], LsRow> {
+fn parse_ls(input: &[u8]) -> IResult<&[u8], LsRow<'_>> {
     terminated(
         map(
             separated_pair(
@@ -146,9 +146,6 @@ struct LsRow<'a> {
 #[derive(Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
 struct DirId(usize);
 
-#[derive(Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
-struct FileId(usize);
-
 struct Fs<'a> {
     files: Vec<FileEntry<'a>>,
     dirs: Vec<DirEntry<'a>>,
@@ -263,5 +260,3 @@ impl<'a> Debug for Fs<'a> {
         print_level(self, DirId(0), &mut String::new(), f)
     }
 }
-
-crate::test_day!(RUN, "day7", "1306611", "13210366");
@@ -12,19 +12,19 @@ use nom::{
 };
 use rustc_hash::FxHashSet;
 
-use crate::{parsers, Day};
+use crate::{parsers, Answer, Day};
 
-pub static RUN: Day = Day { part1, part2 };
+pub static RUN: Day = Day::new(part1, part2).titled("Rope Bridge");
 
-pub fn part1(input: &[u8]) -> anyhow::Result<String> {
+pub fn part1(input: &[u8]) -> anyhow::Result<Answer> {
     trace_rope::<2>(input)
 }
 
-pub fn part2(input: &[u8]) -> anyhow::Result<String> {
+pub fn part2(input: &[u8]) -> anyhow::Result<Answer> {
     trace_rope::<10>(input)
 }
 
-fn trace_rope<const N: usize>(input: &[u8]) -> anyhow::Result<String> {
+fn trace_rope<const N: usize>(input: &[u8]) -> anyhow::Result<Answer> {
     let mut rope = Rope::<N>::new();
     let mut visited = FxHashSet::default();
     visited.insert(Pos { x: 0, y: 0 });
@@ -42,7 +42,7 @@ fn trace_rope<const N: usize>(input: &[u8]) -> anyhow::Result<String> {
         input,
     )?;
 
-    Ok(visited.len().to_string())
+    Ok(visited.len().into())
 }
 
 fn parse_move(input: &[u8]) -> IResult<&[u8], Move> {
@@ -138,24 +138,3 @@ impl<const N: usize> Rope<N> {
         self.knots[N - 1]
     }
 }
-
-#[test]
-fn test_large_example() {
-    assert_eq!(
-        part2(
-            br"R 5
-U 8
-L 8
-D 3
-R 17
-D 10
-L 25
-U 20
-"
-        )
-        .unwrap(),
-        "36".to_string()
-    )
-}
-
-crate::test_day!(RUN, "day9", "5878", "2405");
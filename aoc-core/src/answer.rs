@@ -0,0 +1,253 @@
+//! The typed result of solving a part.
+//!
+//! Answers in the answers files are plain text, which [`Answer::parse`] turns into the same
+//! structure a solution returns, so that e.g. `0042` matches the integer `42` and a picture matches
+//! regardless of trailing whitespace. Pictures of letters are read with [`crate::ocr`], so they
+//! also match the letters written as text.
+
+use std::fmt;
+
+use serde::{Serialize, Serializer};
+
+use crate::ocr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Integer(i64),
+    /// An integer that does not fit into an `i64`.
+    BigInteger(i128),
+    Text(String),
+    /// A picture of lit pixels that shows the answer and could not be read as letters.
+    Image(Image),
+    /// The part has no answer of its own, like part 2 of the last day of a year.
+    NotApplicable,
+}
+
+impl Answer {
+    /// Interpret an answer as written in an answers file or printed by the runner.
+    pub fn parse(text: &str) -> Self {
+        let text = text.trim_end();
+        if text == "n/a" {
+            Answer::NotApplicable
+        } else if let Ok(value) = text.parse::<i64>() {
+            Answer::Integer(value)
+        } else if let Ok(value) = text.parse::<i128>() {
+            Answer::BigInteger(value)
+        } else if let Some(image) = Image::parse(text) {
+            image.into()
+        } else {
+            Answer::Text(text.to_owned())
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(value) => write!(f, "{}", value),
+            Answer::BigInteger(value) => write!(f, "{}", value),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Image(image) => write!(f, "{}", image),
+            Answer::NotApplicable => write!(f, "n/a"),
+        }
+    }
+}
+
+/// Integers are written as JSON numbers unless they may lose precision, pictures as a list of
+/// rows and missing answers as `null`.
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Integer(value) => serializer.serialize_i64(*value),
+            Answer::BigInteger(value) => serializer.collect_str(value),
+            Answer::Text(text) => serializer.serialize_str(text),
+            Answer::Image(image) => serializer.collect_seq(image.rows()),
+            Answer::NotApplicable => serializer.serialize_none(),
+        }
+    }
+}
+
+macro_rules! from_small_int {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(value: $t) -> Self {
+                Answer::Integer(value.into())
+            }
+        })*
+    };
+}
+
+macro_rules! from_large_int {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(value: $t) -> Self {
+                match i64::try_from(value) {
+                    Ok(value) => Answer::Integer(value),
+                    Err(_) => match i128::try_from(value) {
+                        Ok(value) => Answer::BigInteger(value),
+                        Err(_) => Answer::Text(value.to_string()),
+                    },
+                }
+            }
+        })*
+    };
+}
+
+from_small_int!(i8, i16, i32, i64, u8, u16, u32);
+from_large_int!(isize, usize, u64, i128, u128);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_owned())
+    }
+}
+
+/// Pictures showing letters become the letters as text, other pictures stay as they are.
+impl From<Image> for Answer {
+    fn from(image: Image) -> Self {
+        match ocr::recognize(&image) {
+            Some(letters) => Answer::Text(letters),
+            None => Answer::Image(image),
+        }
+    }
+}
+
+/// A rectangle of pixels that are either lit (`#`) or dark (`.`).
+#[derive(Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    pixels: Vec<bool>,
+}
+
+impl Image {
+    /// An image from its pixels in row-major order.
+    pub fn new(width: usize, pixels: Vec<bool>) -> Self {
+        assert!(
+            width > 0 && pixels.len() % width == 0,
+            "{} pixels do not form rows of width {}",
+            pixels.len(),
+            width
+        );
+        Self { width, pixels }
+    }
+
+    /// The smallest image containing the origin in which exactly the given points are lit.
+    pub fn from_points(points: impl IntoIterator<Item = (usize, usize)>) -> Self {
+        let points = points.into_iter().collect::<Vec<_>>();
+        let width = points.iter().map(|&(x, _)| x + 1).max().unwrap_or(1);
+        let height = points.iter().map(|&(_, y)| y + 1).max().unwrap_or(1);
+        let mut pixels = vec![false; width * height];
+        for (x, y) in points {
+            pixels[y * width + x] = true;
+        }
+        Self { width, pixels }
+    }
+
+    /// Parse a picture of at least two rows of `#` and `.` of the same width.
+    pub fn parse(text: &str) -> Option<Self> {
+        let rows = text.lines().map(str::trim_end).collect::<Vec<_>>();
+        let width = rows.first()?.len();
+        if rows.len() < 2 || width == 0 || rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+        let pixels = rows
+            .iter()
+            .flat_map(|row| row.bytes())
+            .map(|ch| match ch {
+                b'#' => Some(true),
+                b'.' => Some(false),
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>()?;
+        Some(Self { width, pixels })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.pixels.len() / self.width
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> bool {
+        x < self.width
+            && self
+                .pixels
+                .get(y * self.width + x)
+                .copied()
+                .unwrap_or(false)
+    }
+
+    /// The rows of the picture, drawn with `#` and `.`.
+    pub fn rows(&self) -> impl Iterator<Item = String> + '_ {
+        self.pixels
+            .chunks(self.width)
+            .map(|row| row.iter().map(|&lit| if lit { '#' } else { '.' }).collect())
+    }
+}
+
+impl fmt::Display for Image {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, row) in self.rows().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", row)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Image {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Image")
+            .field(&self.rows().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+#[test]
+fn test_parse_answer() {
+    assert_eq!(Answer::parse("13180\n"), Answer::from(13180u64));
+    assert_eq!(Answer::parse("-3"), Answer::Integer(-3));
+    assert_eq!(
+        Answer::parse("12345678901234567890"),
+        Answer::from(12345678901234567890u64)
+    );
+    assert_eq!(Answer::parse("2=-1=0"), Answer::from("2=-1=0"));
+    assert_eq!(Answer::parse("n/a"), Answer::NotApplicable);
+
+    let image = Image::from_points([(0, 0), (3, 0), (0, 1), (1, 1), (2, 1), (3, 1)]);
+    assert_eq!((image.width(), image.height()), (4, 2));
+    assert!(image.get(3, 0) && !image.get(1, 0) && !image.get(4, 1));
+    assert_eq!(image.to_string(), "#..#\n####");
+    assert_eq!(Answer::parse("#..#\n####\n"), Answer::Image(image));
+    assert_eq!(Answer::parse("#..#"), Answer::from("#..#"));
+    assert_eq!(Answer::parse("#..#\n###"), Answer::from("#..#\n###"));
+
+    let letters = ".##..###.\n#..#.#..#\n#..#.###.\n####.#..#\n#..#.#..#\n#..#.###.";
+    assert_eq!(Answer::parse(letters), Answer::from("AB"));
+}
+
+#[test]
+fn test_serialize_answer() {
+    let json = |answer: Answer| serde_json::to_string(&answer).unwrap();
+    assert_eq!(json(Answer::Integer(42)), "42");
+    assert_eq!(
+        json(Answer::BigInteger(1 << 70)),
+        "\"1180591620717411303424\""
+    );
+    assert_eq!(json(Answer::from("abc")), "\"abc\"");
+    assert_eq!(
+        json(Answer::Image(Image::new(2, vec![true, false, false, true]))),
+        "[\"#.\",\".#\"]"
+    );
+    assert_eq!(json(Answer::NotApplicable), "null");
+}
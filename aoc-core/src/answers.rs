@@ -0,0 +1,204 @@
+//! Known answers for an input file, stored next to it as `<name>.answers`.
+//!
+//! The file consists of `key: value` lines, e.g.
+//!
+//! ```text
+//! part1: 2330
+//! part2: 2675
+//! ```
+//!
+//! Answers spanning several lines are written on the lines following an empty `key:` line. Lines
+//! like `param.row: 10` set the puzzle parameters for this input (see [`crate::params`]).
+
+use std::{
+    collections::BTreeMap,
+    fmt,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Context;
+
+use crate::{answer::Answer, runner::Part};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    entries: BTreeMap<String, String>,
+}
+
+impl Answers {
+    /// The answers file belonging to the given input file.
+    pub fn path_for(input: &Path) -> PathBuf {
+        input.with_extension("answers")
+    }
+
+    /// Load the answers for the given input file. A missing answers file means that no answers
+    /// are known.
+    pub fn load(input: &Path) -> anyhow::Result<Self> {
+        let path = Self::path_for(input);
+        match std::fs::read_to_string(&path) {
+            Ok(text) => Self::parse(&text).with_context(|| format!("parsing {}", path.display())),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err).with_context(|| format!("reading {}", path.display())),
+        }
+    }
+
+    pub fn parse(text: &str) -> anyhow::Result<Self> {
+        let mut entries = BTreeMap::new();
+        let mut multiline: Option<(String, Vec<&str>)> = None;
+
+        for (index, line) in text.lines().enumerate() {
+            if let Some((key, value)) = parse_entry(line) {
+                if let Some((key, lines)) = multiline.take() {
+                    entries.insert(key, lines.join("\n"));
+                }
+                if value.is_empty() {
+                    multiline = Some((key.to_owned(), Vec::new()));
+                } else {
+                    entries.insert(key.to_owned(), value.to_owned());
+                }
+            } else if let Some((_, lines)) = multiline.as_mut() {
+                lines.push(line);
+            } else if !line.trim().is_empty() {
+                anyhow::bail!("line {}: expected `key: value`", index + 1);
+            }
+        }
+        if let Some((key, lines)) = multiline {
+            entries.insert(key, lines.join("\n"));
+        }
+
+        Ok(Self { entries })
+    }
+
+    /// The `param.<name>` entries as `(name, value)` pairs.
+    pub fn params(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.entries.iter().filter_map(|(key, value)| {
+            key.strip_prefix("param.")
+                .map(|name| (name, value.as_str()))
+        })
+    }
+
+    pub fn get(&self, part: Part) -> Option<&str> {
+        self.entries.get(part_key(part)).map(String::as_str)
+    }
+
+    /// The known answer of a part, parsed with [`Answer::parse`].
+    pub fn expected(&self, part: Part) -> Option<Answer> {
+        self.get(part).map(Answer::parse)
+    }
+
+    /// Compare an answer structurally against the known one.
+    pub fn check(&self, part: Part, answer: &Answer) -> Verdict {
+        match self.expected(part) {
+            None => Verdict::Unknown,
+            Some(expected) if expected == *answer => Verdict::Pass,
+            Some(_) => Verdict::Fail,
+        }
+    }
+
+    pub fn set(&mut self, part: Part, answer: &Answer) {
+        self.entries
+            .insert(part_key(part).to_owned(), answer.to_string());
+    }
+
+    /// Write the answers file of the given input file.
+    pub fn save(&self, input: &Path) -> anyhow::Result<()> {
+        let path = Self::path_for(input);
+        std::fs::write(&path, self.to_string())
+            .with_context(|| format!("writing {}", path.display()))
+    }
+}
+
+/// Writes the answers in the format of the answers files.
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (key, value) in &self.entries {
+            if value.contains('\n') {
+                writeln!(f, "{}:\n{}", key, value)?;
+            } else {
+                writeln!(f, "{}: {}", key, value)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Verdict {
+    Pass,
+    Fail,
+    Unknown,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "PASS"),
+            Verdict::Fail => write!(f, "FAIL"),
+            Verdict::Unknown => write!(f, "UNKNOWN"),
+        }
+    }
+}
+
+fn part_key(part: Part) -> &'static str {
+    match part {
+        Part::One => "part1",
+        Part::Two => "part2",
+    }
+}
+
+/// Split a `key: value` line, where the key consists of lower case letters, digits, `_` and `.`.
+pub(crate) fn parse_entry(line: &str) -> Option<(&str, &str)> {
+    let (key, value) = line.split_once(':')?;
+    let valid_key = !key.is_empty()
+        && key
+            .bytes()
+            .all(|ch| ch.is_ascii_lowercase() || ch.is_ascii_digit() || ch == b'_' || ch == b'.');
+    if valid_key {
+        Some((key, value.trim()))
+    } else {
+        None
+    }
+}
+
+#[test]
+fn test_parse_answers() {
+    let answers = Answers::parse("part1: 2330\npart2: 2675\n").unwrap();
+    assert_eq!(answers.get(Part::One), Some("2330"));
+    assert_eq!(answers.get(Part::Two), Some("2675"));
+    assert_eq!(
+        answers.check(Part::One, &Answer::Integer(2330)),
+        Verdict::Pass
+    );
+    assert_eq!(
+        answers.check(Part::Two, &Answer::Integer(2676)),
+        Verdict::Fail
+    );
+    assert_eq!(
+        answers.check(Part::Two, &Answer::from("2675")),
+        Verdict::Fail
+    );
+
+    let answers = Answers::parse("part2:\n#..#\n####\n").unwrap();
+    assert_eq!(answers.get(Part::One), None);
+    assert_eq!(
+        answers.check(Part::One, &Answer::Integer(1)),
+        Verdict::Unknown
+    );
+    assert_eq!(answers.get(Part::Two), Some("#..#\n####"));
+    let image = crate::answer::Image::from_points([(0, 0), (3, 0), (0, 1), (1, 1), (2, 1), (3, 1)]);
+    assert_eq!(
+        answers.check(Part::Two, &Answer::Image(image)),
+        Verdict::Pass
+    );
+
+    assert!(Answers::parse("part1 2330\n").is_err());
+
+    let mut answers = Answers::parse("part1: 26\nparam.row: 10\n").unwrap();
+    assert_eq!(answers.params().collect::<Vec<_>>(), [("row", "10")]);
+    answers.set(Part::Two, &Answer::from("#..#\n####"));
+    assert_eq!(
+        answers.to_string(),
+        "param.row: 10\npart1: 26\npart2:\n#..#\n####\n"
+    );
+    assert_eq!(Answers::parse(&answers.to_string()).unwrap(), answers);
+}
@@ -0,0 +1,307 @@
+//! Saved benchmark timings that later runs are compared against, as done by `aoc bench --save` and
+//! `aoc bench --compare`.
+//!
+//! A baseline keeps the duration of every run, so that a comparison can tell a real slowdown from
+//! noise.
+
+use std::{
+    io::Write,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    report::{millis, PartResult},
+    stats::Timings,
+};
+
+/// The version of the file format, increased whenever old baselines can no longer be read.
+pub const VERSION: u32 = 1;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Baseline {
+    pub version: u32,
+    /// When the baseline was recorded, in seconds since the Unix epoch.
+    pub created: u64,
+    pub parts: Vec<PartTimings>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartTimings {
+    pub year: u32,
+    pub day: u32,
+    pub part: u8,
+    /// The duration of each run in nanoseconds.
+    pub samples_ns: Vec<u64>,
+}
+
+impl PartTimings {
+    pub fn timings(&self) -> Timings {
+        Timings::from_samples(
+            self.samples_ns
+                .iter()
+                .map(|&nanos| Duration::from_nanos(nanos))
+                .collect(),
+        )
+    }
+}
+
+impl Baseline {
+    pub fn from_results(results: &[PartResult], created: u64) -> Self {
+        let parts = results
+            .iter()
+            .map(|result| PartTimings {
+                year: result.year,
+                day: result.day,
+                part: result.part.number(),
+                samples_ns: result
+                    .timings
+                    .samples()
+                    .iter()
+                    .map(|sample| sample.as_nanos() as u64)
+                    .collect(),
+            })
+            .collect();
+        Self {
+            version: VERSION,
+            created,
+            parts,
+        }
+    }
+
+    /// Where the baseline with the given name is stored: the `baselines` directory of the
+    /// workspace, wherever `aoc bench` is run from.
+    pub fn path_for(name: &str) -> PathBuf {
+        let workspace = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .parent()
+            .expect("aoc-core is part of the workspace");
+        workspace.join("baselines").join(format!("{}.json", name))
+    }
+
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let text =
+            std::fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+        let version = serde_json::from_str::<serde_json::Value>(&text)
+            .ok()
+            .and_then(|value| value["version"].as_u64());
+        anyhow::ensure!(
+            version == Some(VERSION.into()),
+            "{} is not a baseline of version {}, record it again",
+            path.display(),
+            VERSION
+        );
+        serde_json::from_str(&text).with_context(|| format!("parsing {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).with_context(|| format!("creating {}", dir.display()))?;
+        }
+        let mut text = serde_json::to_string_pretty(self)?;
+        text.push('\n');
+        std::fs::write(path, text).with_context(|| format!("writing {}", path.display()))
+    }
+
+    fn get(&self, year: u32, day: u32, part: u8) -> Option<&PartTimings> {
+        self.parts
+            .iter()
+            .find(|p| (p.year, p.day, p.part) == (year, day, part))
+    }
+}
+
+/// The timings of a part in the baseline and in the current run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Change {
+    pub year: u32,
+    pub day: u32,
+    pub part: u8,
+    /// `None` if the part is not in the baseline.
+    pub before: Option<Timings>,
+    pub after: Timings,
+}
+
+impl Change {
+    /// The relative change of the median, e.g. `0.2` if the part got 20% slower.
+    pub fn relative(&self) -> Option<f64> {
+        let before = millis(self.before.as_ref()?.median());
+        (before > 0.0).then(|| millis(self.after.median()) / before - 1.0)
+    }
+
+    /// Whether the difference is unlikely to be noise, according to Welch's t-test at the 95%
+    /// level.
+    pub fn is_significant(&self) -> bool {
+        match &self.before {
+            Some(before) => welch_test(before, &self.after),
+            None => false,
+        }
+    }
+
+    /// A significant slowdown of the median by more than `threshold` (e.g. `0.1` for 10%).
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.is_significant() && self.relative().map_or(false, |change| change > threshold)
+    }
+
+    fn verdict(&self, threshold: f64) -> &'static str {
+        match self.relative() {
+            None => "new",
+            Some(_) if !self.is_significant() => "unchanged",
+            Some(_) if self.is_regression(threshold) => "REGRESSION",
+            Some(change) if change > 0.0 => "slower",
+            Some(_) => "faster",
+        }
+    }
+}
+
+/// Match the results of a run with the parts of the baseline. Parts of the baseline that were not
+/// run are left out.
+pub fn compare(baseline: &Baseline, results: &[PartResult]) -> Vec<Change> {
+    results
+        .iter()
+        .map(|result| {
+            let part = result.part.number();
+            Change {
+                year: result.year,
+                day: result.day,
+                part,
+                before: baseline
+                    .get(result.year, result.day, part)
+                    .map(PartTimings::timings),
+                after: result.timings.clone(),
+            }
+        })
+        .collect()
+}
+
+/// Write a table of the median times before and after with their relative change.
+pub fn write_comparison(
+    out: &mut impl Write,
+    changes: &[Change],
+    threshold: f64,
+) -> std::io::Result<()> {
+    writeln!(out, "year\tday\tpart\tbefore ms\tafter ms\tchange\tverdict")?;
+    for change in changes {
+        let before = match &change.before {
+            Some(before) => format!("{:.3}", millis(before.median())),
+            None => "-".to_owned(),
+        };
+        let relative = match change.relative() {
+            Some(relative) => format!("{:+.1}%", relative * 100.0),
+            None => "-".to_owned(),
+        };
+        writeln!(
+            out,
+            "{}\t{}\t{}\t{}\t{:.3}\t{}\t{}",
+            change.year,
+            change.day,
+            change.part,
+            before,
+            millis(change.after.median()),
+            relative,
+            change.verdict(threshold)
+        )?;
+    }
+    Ok(())
+}
+
+/// Whether the means of the samples differ significantly, at the 95% level.
+fn welch_test(a: &Timings, b: &Timings) -> bool {
+    if a.runs() < 2 || b.runs() < 2 {
+        return false;
+    }
+    let (n_a, n_b) = (a.runs() as f64, b.runs() as f64);
+    let var_a = a.stddev().as_secs_f64().powi(2) / n_a;
+    let var_b = b.stddev().as_secs_f64().powi(2) / n_b;
+    let difference = b.mean().as_secs_f64() - a.mean().as_secs_f64();
+    if var_a + var_b == 0.0 {
+        return difference != 0.0;
+    }
+    let t = difference / (var_a + var_b).sqrt();
+    let degrees_of_freedom =
+        (var_a + var_b).powi(2) / (var_a.powi(2) / (n_a - 1.0) + var_b.powi(2) / (n_b - 1.0));
+    t.abs() > t_critical(degrees_of_freedom)
+}
+
+/// The two-sided 95% critical value of Student's t-distribution, approximated by expanding around
+/// the normal distribution. Within 1% of the exact value from 3 degrees of freedom on.
+fn t_critical(degrees_of_freedom: f64) -> f64 {
+    let z: f64 = 1.959964;
+    let n = degrees_of_freedom;
+    z + (z.powi(3) + z) / (4.0 * n)
+        + (5.0 * z.powi(5) + 16.0 * z.powi(3) + 3.0 * z) / (96.0 * n.powi(2))
+        + (3.0 * z.powi(7) + 19.0 * z.powi(5) + 17.0 * z.powi(3) - 15.0 * z) / (384.0 * n.powi(3))
+}
+
+#[test]
+fn test_t_critical() {
+    for (degrees_of_freedom, expected) in [(3.0, 3.182), (10.0, 2.228), (30.0, 2.042)] {
+        assert!((t_critical(degrees_of_freedom) - expected).abs() / expected < 0.01);
+    }
+}
+
+#[test]
+fn test_path_for() {
+    let path = Baseline::path_for("before");
+    assert!(path.is_absolute());
+    assert!(path.ends_with("baselines/before.json"));
+    let workspace = path.parent().unwrap().parent().unwrap();
+    assert!(workspace.join("aoc-core").is_dir());
+}
+
+#[test]
+fn test_compare() {
+    use crate::{report::Outcome, runner::Part};
+
+    let timings = |millis: &[u64]| {
+        Timings::from_samples(millis.iter().map(|&m| Duration::from_millis(m)).collect())
+    };
+    let result = |day, part, millis: &[u64]| PartResult {
+        year: 2022,
+        day,
+        part,
+        outcome: Outcome::Unsolved,
+        timings: timings(millis),
+        memory: None,
+    };
+    let baseline = Baseline::from_results(
+        &[
+            result(1, Part::One, &[10, 11, 10, 9, 10]),
+            result(1, Part::Two, &[10, 11, 10, 9, 10]),
+            result(2, Part::One, &[10, 11, 10, 9, 10]),
+        ],
+        0,
+    );
+    let path = std::env::temp_dir().join(format!("aoc-baseline-{}.json", std::process::id()));
+    baseline.save(&path).unwrap();
+    assert_eq!(Baseline::load(&path).unwrap(), baseline);
+    std::fs::write(&path, "{\"version\": 0, \"parts\": []}").unwrap();
+    assert!(Baseline::load(&path).is_err());
+    std::fs::remove_file(&path).unwrap();
+
+    let changes = compare(
+        &baseline,
+        &[
+            result(1, Part::One, &[13, 12, 13, 14, 13]),
+            result(1, Part::Two, &[10, 12, 9, 10, 11]),
+            result(2, Part::One, &[8, 9, 8, 7, 8]),
+            result(3, Part::One, &[1]),
+        ],
+    );
+    let verdicts = changes
+        .iter()
+        .map(|change| change.verdict(0.1))
+        .collect::<Vec<_>>();
+    assert_eq!(verdicts, ["REGRESSION", "unchanged", "faster", "new"]);
+    assert!((changes[0].relative().unwrap() - 0.3).abs() < 1e-9);
+    assert!(!changes[0].is_regression(0.5));
+
+    let mut table = Vec::new();
+    write_comparison(&mut table, &changes[..1], 0.1).unwrap();
+    assert_eq!(
+        String::from_utf8(table).unwrap(),
+        "year\tday\tpart\tbefore ms\tafter ms\tchange\tverdict\n\
+         2022\t1\t1\t10.000\t13.000\t+30.0%\tREGRESSION\n"
+    );
+}
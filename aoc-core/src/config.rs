@@ -0,0 +1,122 @@
+//! Settings for talking to the Advent of Code website.
+//!
+//! They are read from `$XDG_CONFIG_HOME/aoc/config` (usually `~/.config/aoc/config`), or the file
+//! named by `AOC_CONFIG`, which consists of `key: value` lines like the answers files:
+//!
+//! ```text
+//! session: 53616c7465645f5f...
+//! base_url: https://adventofcode.com
+//! ```
+//!
+//! The `AOC_SESSION` and `AOC_BASE_URL` environment variables take precedence over the file.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+
+use crate::answers::parse_entry;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    /// The value of the `session` cookie of a logged in browser.
+    pub session: Option<String>,
+    pub base_url: String,
+    /// Where the settings were read from, for error messages.
+    pub path: Option<PathBuf>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            session: None,
+            base_url: DEFAULT_BASE_URL.to_owned(),
+            path: None,
+        }
+    }
+}
+
+impl Config {
+    /// Load the settings from the config file and the environment. A missing config file is fine.
+    pub fn load() -> anyhow::Result<Self> {
+        let mut config = match Self::default_path() {
+            Some(path) => Self::read(&path)?,
+            None => Self::default(),
+        };
+        if let Ok(session) = std::env::var("AOC_SESSION") {
+            config.session = Some(session);
+        }
+        if let Ok(base_url) = std::env::var("AOC_BASE_URL") {
+            config.base_url = base_url;
+        }
+        Ok(config)
+    }
+
+    fn default_path() -> Option<PathBuf> {
+        if let Some(path) = std::env::var_os("AOC_CONFIG") {
+            return Some(path.into());
+        }
+        let config_dir = std::env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|home| Path::new(&home).join(".config")))?;
+        Some(config_dir.join("aoc/config"))
+    }
+
+    pub fn read(path: &Path) -> anyhow::Result<Self> {
+        let mut config = match std::fs::read_to_string(path) {
+            Ok(text) => {
+                Self::parse(&text).with_context(|| format!("parsing {}", path.display()))?
+            }
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Self::default(),
+            Err(err) => return Err(err).with_context(|| format!("reading {}", path.display())),
+        };
+        config.path = Some(path.to_owned());
+        Ok(config)
+    }
+
+    pub fn parse(text: &str) -> anyhow::Result<Self> {
+        let mut config = Self::default();
+        for (index, line) in text.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, value) = parse_entry(line)
+                .with_context(|| format!("line {}: expected `key: value`", index + 1))?;
+            match key {
+                "session" => config.session = Some(value.to_owned()),
+                "base_url" => config.base_url = value.to_owned(),
+                _ => anyhow::bail!("line {}: unknown setting {}", index + 1, key),
+            }
+        }
+        Ok(config)
+    }
+
+    /// The session token, which is required for everything that is specific to a user.
+    pub fn session(&self) -> anyhow::Result<&str> {
+        let location = match &self.path {
+            Some(path) => path.display().to_string(),
+            None => "the config file".to_owned(),
+        };
+        self.session.as_deref().with_context(|| {
+            format!(
+                "no session token configured, add `session: <cookie>` to {} or set AOC_SESSION",
+                location
+            )
+        })
+    }
+}
+
+#[test]
+fn test_parse_config() {
+    let config =
+        Config::parse("# comment\nsession: abc123\n\nbase_url: http://127.0.0.1:8080\n").unwrap();
+    assert_eq!(config.session().unwrap(), "abc123");
+    assert_eq!(config.base_url, "http://127.0.0.1:8080");
+
+    let config = Config::parse("").unwrap();
+    assert_eq!(config.base_url, DEFAULT_BASE_URL);
+    assert!(config.session().is_err());
+
+    assert!(Config::parse("sesion: abc123\n").is_err());
+}
@@ -0,0 +1,214 @@
+//! Simulations that run for far too many steps to simulate them all, but end up in a cycle.
+//!
+//! [`hashed`] and [`brent`] step a state until a key derived from it repeats, recording a metric
+//! after every step. The returned [`Run`] then extrapolates the metric to any number of steps,
+//! assuming that it changes by the same amount in every repetition of the cycle. [`hashed`]
+//! remembers every key it has seen, [`brent`] only needs to keep two states at a time, but steps
+//! the simulation about three times as often.
+
+use std::{
+    hash::Hash,
+    ops::{Add, Mul, Sub},
+};
+
+use rustc_hash::FxHashMap;
+
+/// A repeating part of a sequence of states.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    /// The first step that is part of the cycle.
+    pub start: usize,
+    /// The number of steps until the state repeats.
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The first step that is in the same state as `step`.
+    pub fn equivalent_step(&self, step: usize) -> usize {
+        if step < self.start {
+            step
+        } else {
+            self.start + (step - self.start) % self.length
+        }
+    }
+}
+
+/// The metrics of a simulation up to the end of the first repetition of its cycle.
+#[derive(Debug, Clone)]
+pub struct Run<M> {
+    /// The metric of the initial state, followed by the metric after each step.
+    pub metrics: Vec<M>,
+    /// The cycle, unless the requested number of steps was reached before it was found.
+    pub cycle: Option<Cycle>,
+}
+
+impl<M> Run<M>
+where
+    M: Copy + Add<Output = M> + Sub<Output = M> + Mul<Output = M> + TryFrom<usize>,
+{
+    /// The metric after `steps` steps.
+    ///
+    /// Panics if the simulation was stopped before both reaching `steps` and finding the cycle.
+    pub fn metric_at(&self, steps: usize) -> M {
+        if let Some(&metric) = self.metrics.get(steps) {
+            return metric;
+        }
+        let cycle = self
+            .cycle
+            .expect("simulation stopped before the requested step");
+        let gain = self.metrics[cycle.start + cycle.length] - self.metrics[cycle.start];
+        let repetitions = (steps - cycle.start) / cycle.length;
+        let repetitions = M::try_from(repetitions)
+            .ok()
+            .expect("number of repetitions does not fit into the metric");
+        self.metrics[cycle.equivalent_step(steps)] + repetitions * gain
+    }
+}
+
+/// Runs `step` on `state` until `key` repeats or `steps` steps are done, remembering all keys.
+pub fn hashed<S, K: Hash + Eq, M>(
+    mut state: S,
+    steps: usize,
+    mut step: impl FnMut(&mut S),
+    mut key: impl FnMut(&S) -> K,
+    mut metric: impl FnMut(&S) -> M,
+) -> Run<M> {
+    let mut seen = FxHashMap::default();
+    let mut metrics = Vec::new();
+    loop {
+        let current = metrics.len();
+        metrics.push(metric(&state));
+        if current == steps {
+            return Run {
+                metrics,
+                cycle: None,
+            };
+        }
+        if let Some(start) = seen.insert(key(&state), current) {
+            return Run {
+                metrics,
+                cycle: Some(Cycle {
+                    start,
+                    length: current - start,
+                }),
+            };
+        }
+        step(&mut state);
+    }
+}
+
+/// Runs `step` on `state` until `key` repeats or `steps` steps are done, using Brent's algorithm.
+pub fn brent<S: Clone, K: Eq, M>(
+    initial: S,
+    steps: usize,
+    mut step: impl FnMut(&mut S),
+    mut key: impl FnMut(&S) -> K,
+    metric: impl FnMut(&S) -> M,
+) -> Run<M> {
+    // Find the length of the cycle: the hare runs ahead of the tortoise, which jumps to the hare
+    // whenever the distance between them reaches the next power of two.
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = key(&initial);
+    let mut hare = initial.clone();
+    step(&mut hare);
+    let mut hare_steps = 1;
+    while key(&hare) != tortoise {
+        if hare_steps > steps {
+            return replay(initial, steps, step, metric, None);
+        }
+        if power == length {
+            tortoise = key(&hare);
+            power *= 2;
+            length = 0;
+        }
+        step(&mut hare);
+        hare_steps += 1;
+        length += 1;
+    }
+
+    // Find the start of the cycle: with the hare `length` steps ahead, both meet at its start.
+    let mut tortoise = initial.clone();
+    let mut hare = initial.clone();
+    for _ in 0..length {
+        step(&mut hare);
+    }
+    let mut start = 0;
+    while key(&tortoise) != key(&hare) {
+        step(&mut tortoise);
+        step(&mut hare);
+        start += 1;
+    }
+
+    replay(initial, steps, step, metric, Some(Cycle { start, length }))
+}
+
+/// Records the metrics of the steps that [`Run::metric_at`] needs.
+fn replay<S, M>(
+    mut state: S,
+    steps: usize,
+    mut step: impl FnMut(&mut S),
+    mut metric: impl FnMut(&S) -> M,
+    cycle: Option<Cycle>,
+) -> Run<M> {
+    let last = cycle.map_or(steps, |cycle| steps.min(cycle.start + cycle.length));
+    let mut metrics = vec![metric(&state)];
+    for _ in 0..last {
+        step(&mut state);
+        metrics.push(metric(&state));
+    }
+    Run { metrics, cycle }
+}
+
+#[test]
+fn test_cycle() {
+    // 3, 10, 5, 16, 8, 4, 2, 1, 4, 2, 1, ...
+    let collatz = |n: &mut u64| *n = if *n % 2 == 0 { *n / 2 } else { 3 * *n + 1 };
+    let expected = Cycle {
+        start: 5,
+        length: 3,
+    };
+    for run in [
+        hashed(3u64, usize::MAX, collatz, |&n| n, |&n| n),
+        brent(3u64, usize::MAX, collatz, |&n| n, |&n| n),
+    ] {
+        assert_eq!(run.cycle, Some(expected));
+        assert_eq!(run.metrics, [3, 10, 5, 16, 8, 4, 2, 1, 4]);
+        assert_eq!(run.metric_at(3), 16);
+        assert_eq!(run.metric_at(1000), [4, 2, 1][(1000 - 5) % 3]);
+    }
+    assert_eq!(expected.equivalent_step(4), 4);
+    assert_eq!(expected.equivalent_step(9), 6);
+}
+
+#[test]
+fn test_extrapolate() {
+    // A counter that counts up by 1, 2, 3, 1, 2, 3, ... after a first step of 10
+    let step = |(i, total): &mut (usize, u64)| {
+        *total += if *i == 0 {
+            10
+        } else {
+            ((*i - 1) % 3) as u64 + 1
+        };
+        *i += 1;
+    };
+    let key = |&(i, _): &(usize, u64)| if i == 0 { 0 } else { (i - 1) % 3 + 1 };
+    for run in [
+        hashed((0, 0), 1_000_000, step, key, |&(_, total)| total),
+        brent((0, 0), 1_000_000, step, key, |&(_, total)| total),
+    ] {
+        let mut state = (0, 0);
+        for _ in 0..1_000_000 {
+            step(&mut state);
+        }
+        assert_eq!(run.metric_at(1_000_000), state.1);
+        assert_eq!(run.metric_at(2), 11);
+    }
+
+    let short = hashed((0, 0), 2, step, key, |&(_, total)| total);
+    assert_eq!(short.cycle, None);
+    assert_eq!(short.metric_at(2), 11);
+    let short = brent((0, 0), 2, step, key, |&(_, total)| total);
+    assert_eq!(short.cycle, None);
+    assert_eq!(short.metric_at(2), 11);
+}
@@ -0,0 +1,445 @@
+//! Rectangular grids of cells, as found in many puzzle inputs.
+//!
+//! A [`Grid`] is indexed by a [`Pos`] or an `(x, y)` tuple, with `(0, 0)` in the top left corner.
+//! Cells that are drawn as a single character implement [`Symbol`], so that their grids can be
+//! parsed with [`parse_grid`] and printed with `Display` in the same format.
+
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use nom::{
+    error::{Error, ErrorKind},
+    IResult,
+};
+
+/// The offsets of the four neighbours sharing an edge, in reading order.
+pub const NEIGHBOURS_4: [(i32, i32); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+/// The offsets of the eight neighbours sharing an edge or a corner, in reading order.
+pub const NEIGHBOURS_8: [(i32, i32); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Pos {
+    pub x: u32,
+    pub y: u32,
+}
+
+impl Pos {
+    pub const fn new(x: u32, y: u32) -> Self {
+        Self { x, y }
+    }
+
+    /// The position moved by `(dx, dy)`, or `None` if that leaves the non-negative quadrant.
+    pub fn offset(self, dx: i32, dy: i32) -> Option<Pos> {
+        let x = u32::try_from(i64::from(self.x) + i64::from(dx)).ok()?;
+        let y = u32::try_from(i64::from(self.y) + i64::from(dy)).ok()?;
+        Some(Pos { x, y })
+    }
+
+    pub fn manhattan(self, other: Pos) -> u32 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+impl From<(u32, u32)> for Pos {
+    fn from((x, y): (u32, u32)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl From<Pos> for (u32, u32) {
+    fn from(pos: Pos) -> Self {
+        (pos.x, pos.y)
+    }
+}
+
+/// A cell that is drawn as a single character.
+pub trait Symbol: Sized {
+    fn from_byte(byte: u8) -> Option<Self>;
+
+    fn to_char(&self) -> char;
+}
+
+/// Digits, as used for heights and risk levels.
+impl Symbol for u8 {
+    fn from_byte(byte: u8) -> Option<Self> {
+        byte.is_ascii_digit().then(|| byte - b'0')
+    }
+
+    fn to_char(&self) -> char {
+        char::from(b'0' + self)
+    }
+}
+
+/// `#` for `true` and `.` for `false`.
+impl Symbol for bool {
+    fn from_byte(byte: u8) -> Option<Self> {
+        match byte {
+            b'#' => Some(true),
+            b'.' => Some(false),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        if *self {
+            '#'
+        } else {
+            '.'
+        }
+    }
+}
+
+/// The cells are stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: u32,
+    height: u32,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: u32, height: u32, value: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![value; width as usize * height as usize],
+        }
+    }
+
+    pub fn fill(&mut self, value: T) {
+        self.cells.fill(value);
+    }
+
+    /// A copy surrounded by a border of `size` cells with the given value.
+    pub fn pad(&self, size: u32, value: T) -> Self {
+        let mut padded = Self::new(self.width + 2 * size, self.height + 2 * size, value);
+        for (pos, cell) in self.iter() {
+            padded[(pos.x + size, pos.y + size)] = cell.clone();
+        }
+        padded
+    }
+
+    /// A copy without a border of `size` cells, undoing [`Grid::pad`].
+    pub fn crop(&self, size: u32) -> Self {
+        let width = self.width.saturating_sub(2 * size);
+        let height = self.height.saturating_sub(2 * size);
+        let cells = (0..height)
+            .flat_map(|y| {
+                let start = self.offset(Pos::new(size, y + size));
+                &self.cells[start..start + width as usize]
+            })
+            .cloned()
+            .collect();
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// A grid from its cells, row by row.
+    pub fn from_cells(width: u32, cells: Vec<T>) -> Self {
+        assert!(
+            width > 0 && cells.len() % width as usize == 0,
+            "{} cells do not fill rows of width {}",
+            cells.len(),
+            width
+        );
+        let height = (cells.len() / width as usize) as u32;
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    pub fn contains(&self, pos: impl Into<Pos>) -> bool {
+        let pos = pos.into();
+        pos.x < self.width && pos.y < self.height
+    }
+
+    pub fn get(&self, pos: impl Into<Pos>) -> Option<&T> {
+        let pos = pos.into();
+        self.contains(pos).then(|| &self.cells[self.offset(pos)])
+    }
+
+    pub fn get_mut(&mut self, pos: impl Into<Pos>) -> Option<&mut T> {
+        let pos = pos.into();
+        if self.contains(pos) {
+            let offset = self.offset(pos);
+            Some(&mut self.cells[offset])
+        } else {
+            None
+        }
+    }
+
+    /// The position inside the grid when it repeats infinitely in all directions.
+    pub fn wrapping_pos(&self, x: i64, y: i64) -> Pos {
+        Pos {
+            x: x.rem_euclid(self.width.into()) as u32,
+            y: y.rem_euclid(self.height.into()) as u32,
+        }
+    }
+
+    /// The position inside the grid that is closest to the given one.
+    pub fn clamped_pos(&self, x: i64, y: i64) -> Pos {
+        Pos {
+            x: x.clamp(0, i64::from(self.width) - 1) as u32,
+            y: y.clamp(0, i64::from(self.height) - 1) as u32,
+        }
+    }
+
+    pub fn get_wrapping(&self, x: i64, y: i64) -> &T {
+        &self[self.wrapping_pos(x, y)]
+    }
+
+    pub fn get_clamped(&self, x: i64, y: i64) -> &T {
+        &self[self.clamped_pos(x, y)]
+    }
+
+    /// The index of the cell at `pos` in [`Grid::cells`], e.g. for numbering the nodes of a search.
+    /// Panics if `pos` is outside the grid.
+    pub fn index_of(&self, pos: impl Into<Pos>) -> usize {
+        self.offset(pos.into())
+    }
+
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn cells_mut(&mut self) -> &mut [T] {
+        &mut self.cells
+    }
+
+    /// All positions in reading order.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Pos { x, y }))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Pos, &mut T)> {
+        self.positions().zip(&mut self.cells)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// The neighbours of a position that share an edge with it and are inside the grid. The
+    /// iterator does not borrow the grid, so that the neighbours can be updated while iterating.
+    pub fn neighbours4(&self, pos: impl Into<Pos>) -> impl Iterator<Item = Pos> {
+        self.neighbours(pos.into(), &NEIGHBOURS_4)
+    }
+
+    /// The neighbours of a position that share an edge or a corner with it and are inside the
+    /// grid.
+    pub fn neighbours8(&self, pos: impl Into<Pos>) -> impl Iterator<Item = Pos> {
+        self.neighbours(pos.into(), &NEIGHBOURS_8)
+    }
+
+    fn neighbours(&self, pos: Pos, offsets: &'static [(i32, i32)]) -> impl Iterator<Item = Pos> {
+        let (width, height) = (self.width, self.height);
+        offsets
+            .iter()
+            .filter_map(move |&(dx, dy)| pos.offset(dx, dy))
+            .filter(move |neighbour| neighbour.x < width && neighbour.y < height)
+    }
+
+    /// The positions after `from` in steps of `(dx, dy)`, until leaving the grid.
+    pub fn ray(
+        &self,
+        from: impl Into<Pos>,
+        (dx, dy): (i32, i32),
+    ) -> impl Iterator<Item = Pos> + '_ {
+        std::iter::successors(Some(from.into()), move |pos| pos.offset(dx, dy))
+            .skip(1)
+            .take_while(|&pos| self.contains(pos))
+    }
+
+    pub fn row(&self, y: u32) -> &[T] {
+        let start = self.offset(Pos::new(0, y));
+        &self.cells[start..start + self.width as usize]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks_exact(self.width.max(1) as usize)
+    }
+
+    pub fn column(&self, x: u32) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} is outside the grid", x);
+        self.cells[x as usize..].iter().step_by(self.width as usize)
+    }
+
+    /// The cells from `start` down to the right.
+    pub fn diagonal(&self, start: impl Into<Pos>) -> impl Iterator<Item = &T> {
+        self.line(start.into(), (1, 1))
+    }
+
+    /// The cells from `start` down to the left.
+    pub fn anti_diagonal(&self, start: impl Into<Pos>) -> impl Iterator<Item = &T> {
+        self.line(start.into(), (-1, 1))
+    }
+
+    fn line(&self, start: Pos, step: (i32, i32)) -> impl Iterator<Item = &T> {
+        self.get(start)
+            .into_iter()
+            .chain(self.ray(start, step).map(move |pos| &self[pos]))
+    }
+
+    /// Panics if `pos` is outside the grid, rather than returning a cell of the next row.
+    fn offset(&self, pos: Pos) -> usize {
+        assert!(pos.x < self.width, "{:?} is outside the grid", pos);
+        pos.x as usize + pos.y as usize * self.width as usize
+    }
+}
+
+impl<T, P: Into<Pos>> Index<P> for Grid<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, pos: P) -> &T {
+        &self.cells[self.offset(pos.into())]
+    }
+}
+
+impl<T, P: Into<Pos>> IndexMut<P> for Grid<T> {
+    #[inline]
+    fn index_mut(&mut self, pos: P) -> &mut T {
+        let offset = self.offset(pos.into());
+        &mut self.cells[offset]
+    }
+}
+
+/// One line per row, in the format read by [`parse_grid`].
+impl<T: Symbol> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell.to_char())?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Parse a grid of [`Symbol`]s, see [`parse_grid_with`].
+pub fn parse_grid<T: Symbol>(input: &[u8]) -> IResult<&[u8], Grid<T>> {
+    parse_grid_with(T::from_byte)(input)
+}
+
+/// Parse lines of the same width with one cell per byte, until the end of the input or an empty
+/// line. The newline after the last row is optional.
+pub fn parse_grid_with<'a, T>(
+    mut cell: impl FnMut(u8) -> Option<T>,
+) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], Grid<T>> {
+    move |input| {
+        let mut rest = input;
+        let mut width = None;
+        let mut cells = Vec::new();
+        while !matches!(rest, [] | [b'\n', ..]) {
+            let len = rest.iter().position(|&b| b == b'\n').unwrap_or(rest.len());
+            if *width.get_or_insert(len) != len {
+                return Err(nom::Err::Error(Error::new(rest, ErrorKind::Verify)));
+            }
+            for (index, &byte) in rest[..len].iter().enumerate() {
+                match cell(byte) {
+                    Some(value) => cells.push(value),
+                    None => {
+                        let position = &rest[index..];
+                        return Err(nom::Err::Error(Error::new(position, ErrorKind::MapOpt)));
+                    }
+                }
+            }
+            rest = rest[len..].strip_prefix(b"\n").unwrap_or(&rest[len..]);
+        }
+        match width {
+            Some(width) => Ok((rest, Grid::from_cells(width as u32, cells))),
+            None => Err(nom::Err::Error(Error::new(input, ErrorKind::Many1))),
+        }
+    }
+}
+
+#[test]
+fn test_parse_display() {
+    let text = "#..\n.#.\n..#\n";
+    let grid: Grid<bool> = crate::parsers::parse(parse_grid, text.as_bytes()).unwrap();
+    assert_eq!((grid.width(), grid.height()), (3, 3));
+    assert!(grid[(1, 1)] && !grid[Pos::new(1, 0)]);
+    assert_eq!(grid.to_string(), text);
+
+    let mut digits = parse_grid_with(u8::from_byte);
+    assert_eq!(
+        digits(b"12\n34\n\nrest").unwrap(),
+        (b"\nrest".as_slice(), Grid::from_cells(2, vec![1, 2, 3, 4]))
+    );
+    assert_eq!(
+        parse_grid::<u8>(b"12").unwrap().1,
+        Grid::from_cells(2, vec![1, 2])
+    );
+    assert!(parse_grid::<u8>(b"12\n345\n").is_err());
+    assert!(parse_grid::<u8>(b"12\n3x\n").is_err());
+    assert!(parse_grid::<u8>(b"\n").is_err());
+}
+
+#[test]
+fn test_neighbours() {
+    let grid = Grid::new(3, 2, 0);
+    let corner = grid.neighbours4((0, 0)).collect::<Vec<_>>();
+    assert_eq!(corner, [Pos::new(1, 0), Pos::new(0, 1)]);
+    assert_eq!(grid.neighbours4((1, 1)).count(), 3);
+    assert_eq!(grid.neighbours8((1, 0)).count(), 5);
+    assert_eq!(grid.neighbours8((2, 1)).count(), 3);
+}
+
+#[test]
+fn test_access() {
+    let mut grid = Grid::from_cells(3, (0..6).collect());
+    assert_eq!(grid.get((2, 1)), Some(&5));
+    assert_eq!(grid.get((3, 0)), None);
+    assert!(std::panic::catch_unwind(|| grid[(3, 0)]).is_err());
+    assert_eq!(*grid.get_wrapping(-1, 2), 2);
+    assert_eq!(*grid.get_clamped(5, -3), 2);
+    assert_eq!(grid.row(1), [3, 4, 5]);
+    assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), [1, 4]);
+    assert_eq!(grid.diagonal((0, 0)).copied().collect::<Vec<_>>(), [0, 4]);
+    assert_eq!(
+        grid.anti_diagonal((2, 0)).copied().collect::<Vec<_>>(),
+        [2, 4]
+    );
+    let ray = grid.ray((2, 1), (-1, 0)).collect::<Vec<_>>();
+    assert_eq!(ray, [Pos::new(1, 1), Pos::new(0, 1)]);
+    *grid.get_mut((0, 1)).unwrap() = 9;
+    assert_eq!(grid.pad(1, 0).crop(1), grid);
+    assert_eq!(grid.pad(2, 7).width(), 7);
+}
@@ -0,0 +1,163 @@
+//! Tests generated from the answers files next to the inputs.
+//!
+//! The build script of each year calls [`generate`], which emits one test per part for every
+//! `inputs/dayN/<name>.txt` that has a `<name>.answers` file, solved by day `N` of the `YEAR` of
+//! the package. The year includes them with
+//!
+//! ```ignore
+//! #[cfg(test)]
+//! mod input_tests {
+//!     include!(concat!(env!("OUT_DIR"), "/input_tests.rs"));
+//! }
+//! ```
+
+use std::{collections::HashSet, fmt::Write, path::Path};
+
+use anyhow::Context;
+
+use crate::{
+    answers::Answers,
+    normalize::normalize,
+    runner::{self, Day, Part},
+};
+
+/// Generate `$OUT_DIR/input_tests.rs` from the `inputs` directory of the package being built.
+pub fn generate() {
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR is set");
+    let out_dir = std::env::var("OUT_DIR").expect("OUT_DIR is set");
+    println!("cargo:rerun-if-changed=inputs");
+
+    let code = generate_tests(&Path::new(&manifest_dir).join("inputs"))
+        .expect("generating tests from inputs");
+    std::fs::write(Path::new(&out_dir).join("input_tests.rs"), code)
+        .expect("writing generated tests");
+}
+
+fn generate_tests(inputs: &Path) -> anyhow::Result<String> {
+    let mut cases = Vec::new();
+    for entry in
+        std::fs::read_dir(inputs).with_context(|| format!("reading {}", inputs.display()))?
+    {
+        let day_dir = entry?.path();
+        let Some(day) = day_dir
+            .file_name()
+            .and_then(|name| name.to_str()?.strip_prefix("day")?.parse::<u32>().ok())
+        else {
+            continue;
+        };
+        for entry in std::fs::read_dir(&day_dir)? {
+            let input = entry?.path();
+            if input.extension().map_or(true, |ext| ext != "txt") {
+                continue;
+            }
+            let answers = Answers::load(&input)?;
+            let name = input
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .with_context(|| format!("invalid file name {}", input.display()))?
+                .to_owned();
+            for part in [Part::One, Part::Two] {
+                if answers.get(part).is_some() {
+                    cases.push((day, name.clone(), part));
+                }
+            }
+        }
+    }
+    cases.sort();
+
+    let mut code = String::from("// Generated by aoc_core::input_tests::generate.\n");
+    let mut tests = HashSet::new();
+    for (day, name, part) in cases {
+        let ident: String = name
+            .chars()
+            .map(|ch| if ch.is_ascii_alphanumeric() { ch } else { '_' })
+            .collect();
+        // Names like `example-large` and `example_large` map to the same identifier.
+        let mut test = format!("day{}_{}_part{}", day, ident, part.number());
+        for suffix in 2.. {
+            if tests.insert(test.clone()) {
+                break;
+            }
+            test = format!("day{}_{}_part{}_{}", day, ident, part.number(), suffix);
+        }
+        let path = format!("/inputs/day{}/{}.txt", day, name);
+        write!(
+            code,
+            r#"
+#[test]
+fn {test}() {{
+    aoc_core::input_tests::check(
+        crate::YEAR.day({day}).expect("day {day} is registered"),
+        aoc_core::runner::Part::{part:?},
+        concat!(env!("CARGO_MANIFEST_DIR"), {path:?}),
+    );
+}}
+"#,
+            test = test,
+            day = day,
+            part = part,
+            path = path,
+        )?;
+    }
+    Ok(code)
+}
+
+/// Solve a part for the given input file and compare the result with its answers file, the same
+/// way the runner does.
+pub fn check(day: &Day, part: Part, input: &str) {
+    let input = Path::new(input);
+    let contents =
+        std::fs::read(input).unwrap_or_else(|err| panic!("reading {}: {}", input.display(), err));
+    let (contents, _) = normalize(&contents);
+    let answers = Answers::load(input).unwrap_or_else(|err| panic!("{:#}", err));
+    let params =
+        runner::resolve_params(day, &answers, &[]).unwrap_or_else(|err| panic!("{:#}", err));
+
+    let expected = answers.expected(part).expect("the part has an answer");
+    let output = day
+        .solve_with(part, &contents, &params)
+        .unwrap_or_else(|err| panic!("part {} should work: {:#}", part.number(), err));
+    assert_eq!(
+        output,
+        expected,
+        "part {} of {}",
+        part.number(),
+        input.display()
+    );
+}
+
+#[test]
+fn test_generate_tests() {
+    let dir = std::env::temp_dir().join(format!("aoc-input-tests-{}", std::process::id()));
+    let day_dir = dir.join("day9");
+    std::fs::create_dir_all(&day_dir).unwrap();
+    std::fs::write(day_dir.join("input.txt"), "R 4\n").unwrap();
+    std::fs::write(day_dir.join("input.answers"), "part1: 13\npart2: 1\n").unwrap();
+    std::fs::write(day_dir.join("example-large.txt"), "R 5\n").unwrap();
+    std::fs::write(day_dir.join("example-large.answers"), "part2: 36\n").unwrap();
+    std::fs::write(day_dir.join("example.txt"), "R 4\n").unwrap();
+    std::fs::write(day_dir.join("example_large.txt"), "R 5\n").unwrap();
+    std::fs::write(day_dir.join("example_large.answers"), "part2: 36\n").unwrap();
+    std::fs::write(day_dir.join("quote\".txt"), "R 4\n").unwrap();
+    std::fs::write(day_dir.join("quote\".answers"), "part1: 13\n").unwrap();
+
+    let code = generate_tests(&dir).unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    let tests = code
+        .lines()
+        .filter_map(|line| line.strip_prefix("fn "))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        tests,
+        [
+            "day9_example_large_part2() {",
+            "day9_example_large_part2_2() {",
+            "day9_input_part1() {",
+            "day9_input_part2() {",
+            "day9_quote__part1() {",
+        ]
+    );
+    assert!(code.contains(r#""/inputs/day9/example-large.txt")"#));
+    assert!(code.contains(r#""/inputs/day9/quote\".txt")"#));
+}
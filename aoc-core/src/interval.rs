@@ -0,0 +1,453 @@
+//! Sets of integers made of intervals, and sets of points made of axis-aligned boxes.
+//!
+//! Intervals are half-open, an [`Interval`] contains `start` but not `end`. The inclusive ranges
+//! used by most puzzles are converted with [`Interval::inclusive`] or `From<RangeInclusive>`.
+//! Since `end` is excluded, the largest value of the type cannot be part of an interval, so
+//! [`Interval::inclusive`] panics for ranges that end there.
+
+use std::ops::{Range, RangeInclusive};
+
+/// The integer types that intervals are made of.
+pub trait Coord: Copy + Ord {
+    const MIN: Self;
+    const MAX: Self;
+
+    /// The next larger integer, or `None` for `MAX`.
+    fn checked_succ(self) -> Option<Self>;
+
+    /// The number of integers from `self` up to but excluding `to`.
+    fn distance(self, to: Self) -> u64;
+}
+
+macro_rules! impl_coord {
+    ($($t:ty),*) => {
+        $(impl Coord for $t {
+            const MIN: Self = <$t>::MIN;
+            const MAX: Self = <$t>::MAX;
+
+            fn checked_succ(self) -> Option<Self> {
+                self.checked_add(1)
+            }
+
+            fn distance(self, to: Self) -> u64 {
+                (to as i128 - self as i128) as u64
+            }
+        })*
+    };
+}
+
+impl_coord!(i32, i64, u32, u64, usize);
+
+/// The integers from `start` up to but excluding `end`. Empty if `end <= start`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: Coord> Interval<T> {
+    pub const fn new(start: T, end: T) -> Self {
+        Interval { start, end }
+    }
+
+    /// The integers from `first` to `last`, both included.
+    ///
+    /// Panics if `last` is `T::MAX`, which an interval cannot contain.
+    pub fn inclusive(first: T, last: T) -> Self {
+        let end = last
+            .checked_succ()
+            .expect("the largest value of a type cannot be part of an interval");
+        Interval::new(first, end)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    /// The number of integers in the interval.
+    pub fn len(&self) -> u64 {
+        if self.is_empty() {
+            0
+        } else {
+            self.start.distance(self.end)
+        }
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    /// Whether all of `other` is part of this interval.
+    pub fn contains_interval(&self, other: &Self) -> bool {
+        other.is_empty() || (self.start <= other.start && other.end <= self.end)
+    }
+
+    /// Whether the intervals have any integer in common.
+    pub fn overlaps(&self, other: &Self) -> bool {
+        !self.intersect(other).is_empty()
+    }
+
+    /// The integers in both intervals, which may be empty.
+    pub fn intersect(&self, other: &Self) -> Self {
+        Interval::new(self.start.max(other.start), self.end.min(other.end))
+    }
+}
+
+impl<T: Coord> From<Range<T>> for Interval<T> {
+    fn from(range: Range<T>) -> Self {
+        Interval::new(range.start, range.end)
+    }
+}
+
+impl<T: Coord> From<RangeInclusive<T>> for Interval<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        Interval::inclusive(*range.start(), *range.end())
+    }
+}
+
+/// A set of integers, stored as sorted intervals that neither overlap nor touch.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        IntervalSet {
+            intervals: Vec::new(),
+        }
+    }
+}
+
+impl<T: Coord> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The disjoint intervals of the set, in ascending order.
+    pub fn intervals(&self) -> &[Interval<T>] {
+        &self.intervals
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// The number of integers in the set.
+    pub fn len(&self) -> u64 {
+        self.intervals.iter().map(Interval::len).sum()
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let after = self.intervals.partition_point(|i| i.end <= value);
+        self.intervals
+            .get(after)
+            .map_or(false, |interval| interval.contains(value))
+    }
+
+    pub fn insert(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+        let first = self.intervals.partition_point(|i| i.end < interval.start);
+        let last = self.intervals.partition_point(|i| i.start <= interval.end);
+        let merged = self.intervals[first..last]
+            .iter()
+            .fold(interval, |merged, i| {
+                Interval::new(merged.start.min(i.start), merged.end.max(i.end))
+            });
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        self.intervals
+            .iter()
+            .chain(&other.intervals)
+            .copied()
+            .collect()
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intervals = Vec::new();
+        let (mut a, mut b) = (self.intervals.iter(), other.intervals.iter());
+        let (mut next_a, mut next_b) = (a.next(), b.next());
+        while let (Some(ia), Some(ib)) = (next_a, next_b) {
+            let common = ia.intersect(ib);
+            if !common.is_empty() {
+                intervals.push(common);
+            }
+            if ia.end <= ib.end {
+                next_a = a.next();
+            } else {
+                next_b = b.next();
+            }
+        }
+        IntervalSet { intervals }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        self.intersection(&other.complement())
+    }
+
+    /// All integers from `T::MIN` up to but excluding `T::MAX` that are not in the set.
+    pub fn complement(&self) -> Self {
+        let mut intervals = Vec::new();
+        let mut start = T::MIN;
+        for interval in &self.intervals {
+            if start < interval.start {
+                intervals.push(Interval::new(start, interval.start));
+            }
+            start = interval.end;
+        }
+        if start < T::MAX {
+            intervals.push(Interval::new(start, T::MAX));
+        }
+        IntervalSet { intervals }
+    }
+}
+
+impl<T: Coord> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        let mut sorted: Vec<_> = iter.into_iter().filter(|i| !i.is_empty()).collect();
+        sorted.sort_unstable_by_key(|i| i.start);
+        let mut intervals: Vec<Interval<T>> = Vec::with_capacity(sorted.len());
+        for interval in sorted {
+            match intervals.last_mut() {
+                Some(last) if interval.start <= last.end => last.end = last.end.max(interval.end),
+                _ => intervals.push(interval),
+            }
+        }
+        IntervalSet { intervals }
+    }
+}
+
+/// An axis-aligned box in `N` dimensions, the product of one interval per axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cuboid<T, const N: usize> {
+    pub axes: [Interval<T>; N],
+}
+
+impl<T: Coord, const N: usize> Cuboid<T, N> {
+    pub const fn new(axes: [Interval<T>; N]) -> Self {
+        Cuboid { axes }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.axes.iter().any(Interval::is_empty)
+    }
+
+    /// The number of integer points in the box, or `None` if it does not fit into a `u64`.
+    pub fn volume(&self) -> Option<u64> {
+        self.axes
+            .iter()
+            .try_fold(1u64, |volume, axis| volume.checked_mul(axis.len()))
+    }
+
+    pub fn contains(&self, point: [T; N]) -> bool {
+        self.axes
+            .iter()
+            .zip(point)
+            .all(|(axis, x)| axis.contains(x))
+    }
+
+    /// The points in both boxes, which may be empty.
+    pub fn intersect(&self, other: &Self) -> Self {
+        let mut axes = self.axes;
+        for (axis, other) in axes.iter_mut().zip(&other.axes) {
+            *axis = axis.intersect(other);
+        }
+        Cuboid { axes }
+    }
+
+    /// Appends at most `2 * N` disjoint boxes covering the points of `self` that are not in
+    /// `other` to `output`.
+    pub fn subtract(&self, other: &Self, output: &mut Vec<Self>) {
+        let chunk = self.intersect(other);
+        if chunk.is_empty() {
+            output.push(*self);
+            return;
+        }
+        // Cut off the slabs below and above the chunk along each axis in turn. Along the later
+        // axes, only the part within the chunk on the earlier axes is left.
+        let mut rest = *self;
+        for axis in 0..N {
+            let (outer, inner) = (rest.axes[axis], chunk.axes[axis]);
+            for slab in [
+                Interval::new(outer.start, inner.start),
+                Interval::new(inner.end, outer.end),
+            ] {
+                if !slab.is_empty() {
+                    let mut piece = rest;
+                    piece.axes[axis] = slab;
+                    output.push(piece);
+                }
+            }
+            rest.axes[axis] = inner;
+        }
+    }
+}
+
+/// A set of points, stored as disjoint boxes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CuboidSet<T, const N: usize> {
+    cuboids: Vec<Cuboid<T, N>>,
+}
+
+impl<T, const N: usize> Default for CuboidSet<T, N> {
+    fn default() -> Self {
+        CuboidSet {
+            cuboids: Vec::new(),
+        }
+    }
+}
+
+impl<T: Coord, const N: usize> CuboidSet<T, N> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The disjoint boxes of the set, in no particular order.
+    pub fn cuboids(&self) -> &[Cuboid<T, N>] {
+        &self.cuboids
+    }
+
+    /// The number of integer points in the set, or `None` if it does not fit into a `u64`.
+    pub fn volume(&self) -> Option<u64> {
+        self.cuboids
+            .iter()
+            .try_fold(0u64, |volume, cuboid| volume.checked_add(cuboid.volume()?))
+    }
+
+    pub fn contains(&self, point: [T; N]) -> bool {
+        self.cuboids.iter().any(|cuboid| cuboid.contains(point))
+    }
+
+    /// Adds the points of `cuboid`, keeping only the parts that are not in the set yet.
+    pub fn insert(&mut self, cuboid: Cuboid<T, N>) {
+        if cuboid.is_empty() {
+            return;
+        }
+        let mut new = vec![cuboid];
+        let mut tmp = Vec::new();
+        for existing in &self.cuboids {
+            for piece in &new {
+                piece.subtract(existing, &mut tmp);
+            }
+            std::mem::swap(&mut new, &mut tmp);
+            tmp.clear();
+            if new.is_empty() {
+                return;
+            }
+        }
+        self.cuboids.append(&mut new);
+    }
+
+    /// Removes the points of `cuboid`.
+    pub fn remove(&mut self, cuboid: &Cuboid<T, N>) {
+        let mut remaining = Vec::with_capacity(self.cuboids.len());
+        for existing in self.cuboids.drain(..) {
+            existing.subtract(cuboid, &mut remaining);
+        }
+        self.cuboids = remaining;
+    }
+}
+
+#[test]
+fn test_interval() {
+    let a = Interval::inclusive(2, 8);
+    assert_eq!(a, Interval::from(2..9));
+    assert_eq!(a.len(), 7);
+    assert!(a.contains(8) && !a.contains(9));
+    assert!(a.contains_interval(&(3..=8).into()));
+    assert!(!a.contains_interval(&(3..=9).into()));
+    assert!(a.overlaps(&(8..=9).into()));
+    assert!(!a.overlaps(&(9..=10).into()));
+    assert!(a.intersect(&Interval::new(9, 12)).is_empty());
+    assert_eq!(Interval::new(5, 3).len(), 0);
+
+    let below_max = Interval::inclusive(u32::MAX - 2, u32::MAX - 1);
+    assert_eq!(below_max, Interval::new(u32::MAX - 2, u32::MAX));
+    assert_eq!(below_max.len(), 2);
+    assert!(below_max.contains(u32::MAX - 1) && !below_max.contains(u32::MAX));
+    assert_eq!(Interval::from(i64::MIN..=i64::MAX - 1).len(), u64::MAX);
+    assert!(std::panic::catch_unwind(|| Interval::inclusive(u32::MAX - 2, u32::MAX)).is_err());
+    assert!(std::panic::catch_unwind(|| Interval::from(i64::MIN..=i64::MAX)).is_err());
+}
+
+#[test]
+fn test_interval_set() {
+    let set: IntervalSet<i32> = [(-3..=2), (10..=12), (0..=5), (6..=6)]
+        .into_iter()
+        .map(Interval::from)
+        .collect();
+    assert_eq!(
+        set.intervals(),
+        [Interval::new(-3, 7), Interval::new(10, 13)]
+    );
+    assert_eq!(set.len(), 13);
+    assert!(set.contains(6) && !set.contains(7) && set.contains(12));
+
+    let other: IntervalSet<i32> = [Interval::new(5, 11), Interval::new(20, 21)]
+        .into_iter()
+        .collect();
+    assert_eq!(
+        set.intersection(&other).intervals(),
+        [Interval::new(5, 7), Interval::new(10, 11)]
+    );
+    assert_eq!(
+        set.difference(&other).intervals(),
+        [Interval::new(-3, 5), Interval::new(11, 13)]
+    );
+    assert_eq!(
+        set.union(&other).intervals(),
+        [Interval::new(-3, 13), Interval::new(20, 21)]
+    );
+    assert_eq!(set.complement().complement(), set);
+    assert_eq!(
+        IntervalSet::<u32>::new().complement().intervals(),
+        [Interval::new(0, u32::MAX)]
+    );
+
+    let mut inserted = set.clone();
+    inserted.insert(Interval::new(7, 10));
+    assert_eq!(inserted.intervals(), [Interval::new(-3, 13)]);
+    inserted.insert(Interval::new(15, 17));
+    inserted.insert(Interval::new(-10, -5));
+    assert_eq!(inserted.len(), 16 + 2 + 5);
+}
+
+#[test]
+fn test_cuboid_set() {
+    let cube = |min: i32, max: i32| Cuboid::new([Interval::inclusive(min, max); 3]);
+    let mut set = CuboidSet::new();
+    set.insert(cube(0, 2));
+    set.insert(cube(1, 3));
+    assert_eq!(set.volume(), Some(27 + 27 - 8));
+    set.remove(&cube(2, 2));
+    assert_eq!(set.volume(), Some(27 + 27 - 8 - 1));
+    assert!(!set.contains([2, 2, 2]) && set.contains([3, 3, 3]) && set.contains([0, 1, 2]));
+    set.insert(cube(-5, 5));
+    assert_eq!(set.volume(), Some(11 * 11 * 11));
+
+    let mut pieces = Vec::new();
+    cube(0, 3).subtract(&cube(1, 2), &mut pieces);
+    assert_eq!(pieces.len(), 6);
+    assert_eq!(
+        pieces.iter().map(|p| p.volume().unwrap()).sum::<u64>(),
+        64 - 8
+    );
+}
+
+#[test]
+fn test_volume_overflow() {
+    // 2^21 points per axis make 2^63 points, but 2^22 would be 2^66.
+    let cube = |len: i64| Cuboid::new([Interval::new(-len / 2, len / 2); 3]);
+    assert_eq!(cube(1 << 21).volume(), Some(1 << 63));
+    assert_eq!(cube(1 << 22).volume(), None);
+
+    let mut set = CuboidSet::new();
+    set.insert(cube(1 << 21));
+    assert_eq!(set.volume(), Some(1 << 63));
+    set.insert(Cuboid::new([Interval::new(1 << 40, 1 << 62); 3]));
+    assert_eq!(set.volume(), None);
+}
@@ -0,0 +1,140 @@
+//! Runner, parsers and test helpers shared by the solutions of all years.
+
+use std::{
+    io::{BufRead, BufReader, Read},
+    marker::PhantomData,
+    str::FromStr,
+};
+
+pub mod answer;
+pub mod answers;
+pub mod baseline;
+pub mod config;
+pub mod cycle;
+pub mod grid;
+pub mod input_tests;
+pub mod interval;
+pub mod memory;
+pub mod normalize;
+pub mod ocr;
+pub mod params;
+pub mod parsers;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod search;
+pub mod stats;
+pub mod submissions;
+pub mod vector;
+pub mod website;
+pub use answer::Answer;
+pub use runner::{aoc_main, Day, Year};
+
+pub struct FileParser<R> {
+    file: BufReader<R>,
+    buffer: String,
+    error: Option<std::io::Error>,
+}
+
+impl<R: Read> FileParser<R> {
+    pub fn new(file: R) -> Self {
+        Self {
+            file: BufReader::new(file),
+            buffer: String::new(),
+            error: None,
+        }
+    }
+
+    pub fn iter_parse<T>(&mut self) -> ParseIter<'_, R, T> {
+        ParseIter {
+            parser: self,
+            output: PhantomData,
+        }
+    }
+
+    pub fn parse_line<T: FromStr>(&mut self) -> Option<T> {
+        self.buffer.clear();
+        match self.file.read_line(&mut self.buffer) {
+            Ok(n) => {
+                if n == 0 {
+                    None
+                } else {
+                    match self.buffer.trim_end_matches('\n').parse::<T>() {
+                        Ok(val) => Some(val),
+                        Err(_err) => {
+                            self.error = Some(std::io::Error::new(
+                                std::io::ErrorKind::InvalidData,
+                                "no parse",
+                            ));
+                            None
+                        }
+                    }
+                }
+            }
+            Err(err) => {
+                self.error = Some(err);
+                None
+            }
+        }
+    }
+
+    pub fn read_line(&mut self) -> Option<&str> {
+        self.buffer.clear();
+        match self.file.read_line(&mut self.buffer) {
+            Ok(n) => {
+                if n == 0 {
+                    None
+                } else {
+                    Some(self.buffer.trim_end_matches('\n'))
+                }
+            }
+            Err(err) => {
+                self.error = Some(err);
+                None
+            }
+        }
+    }
+
+    pub fn finish(self) -> std::io::Result<()> {
+        match self.error {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+}
+
+pub struct ParseIter<'a, R, T> {
+    parser: &'a mut FileParser<R>,
+    output: PhantomData<T>,
+}
+
+impl<'a, R, T> Iterator for ParseIter<'a, R, T>
+where
+    R: Read,
+    T: FromStr,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.parser.parse_line()
+    }
+}
+
+/// Declare the day modules of a year and register their `RUN` in `DAYS`, in order starting with
+/// day 1. Each module has to define `pub static RUN: Day`.
+///
+/// ```ignore
+/// aoc_core::days! {
+///     day1,
+///     day2,
+/// }
+/// ```
+#[macro_export]
+macro_rules! days {
+    ($($day:ident),* $(,)?) => {
+        $(pub mod $day;)*
+
+        /// The solutions of all days, starting with day 1.
+        pub static DAYS: &[$crate::Day] = &[$($day::RUN),*];
+    };
+}
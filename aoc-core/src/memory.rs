@@ -0,0 +1,171 @@
+//! Counting the memory a solution allocates, for `--mem`.
+//!
+//! This only works in binaries that use [`CountingAllocator`] as their global allocator:
+//!
+//! ```ignore
+//! #[global_allocator]
+//! static ALLOCATOR: aoc_core::memory::CountingAllocator = aoc_core::memory::CountingAllocator;
+//! ```
+//!
+//! Nothing is counted until [`enable`] is called. The counters are shared by all threads, so only
+//! one part may run at a time while measuring.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    fmt,
+    sync::atomic::{AtomicBool, AtomicIsize, AtomicUsize, Ordering::Relaxed},
+};
+
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+static ENABLED: AtomicBool = AtomicBool::new(false);
+/// Bytes allocated and not freed since the start of the measurement. Negative if more memory that
+/// was allocated before the start has been freed.
+static CURRENT: AtomicIsize = AtomicIsize::new(0);
+static PEAK: AtomicIsize = AtomicIsize::new(0);
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+/// The system allocator, counting the allocations while measuring. Growing or shrinking an
+/// allocation counts as a new allocation.
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record_dealloc(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            record_dealloc(layout.size());
+            record_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
+fn record_alloc(size: usize) {
+    if !INSTALLED.load(Relaxed) {
+        INSTALLED.store(true, Relaxed);
+    }
+    if ENABLED.load(Relaxed) {
+        ALLOCATIONS.fetch_add(1, Relaxed);
+        ALLOCATED.fetch_add(size, Relaxed);
+        let current = CURRENT.fetch_add(size as isize, Relaxed) + size as isize;
+        PEAK.fetch_max(current, Relaxed);
+    }
+}
+
+fn record_dealloc(size: usize) {
+    if ENABLED.load(Relaxed) {
+        CURRENT.fetch_sub(size as isize, Relaxed);
+    }
+}
+
+/// Start counting allocations, failing if the binary does not use [`CountingAllocator`].
+pub fn enable() -> anyhow::Result<()> {
+    anyhow::ensure!(
+        INSTALLED.load(Relaxed),
+        "counting memory needs `aoc_core::memory::CountingAllocator` as the global allocator"
+    );
+    ENABLED.store(true, Relaxed);
+    Ok(())
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Relaxed)
+}
+
+/// The memory allocated by one run of a part.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MemoryUsage {
+    /// The most memory that was allocated at the same time, on top of what was allocated before.
+    pub peak_bytes: usize,
+    /// The sum of the sizes of all allocations.
+    pub allocated_bytes: usize,
+    pub allocations: usize,
+}
+
+impl fmt::Display for MemoryUsage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "peak {}, {} allocations of {} in total",
+            Bytes(self.peak_bytes),
+            self.allocations,
+            Bytes(self.allocated_bytes)
+        )
+    }
+}
+
+/// A number of bytes in the largest binary unit that keeps it at least 1.
+struct Bytes(usize);
+
+impl fmt::Display for Bytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut value = self.0 as f64;
+        for unit in ["B", "KiB", "MiB"] {
+            if value < 1024.0 {
+                return if unit == "B" {
+                    write!(f, "{} {}", self.0, unit)
+                } else {
+                    write!(f, "{:.1} {}", value, unit)
+                };
+            }
+            value /= 1024.0;
+        }
+        write!(f, "{:.1} GiB", value)
+    }
+}
+
+/// Run `f` and count what it allocates. Returns `None` for the usage unless counting is enabled.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<MemoryUsage>) {
+    if !is_enabled() {
+        return (f(), None);
+    }
+    CURRENT.store(0, Relaxed);
+    PEAK.store(0, Relaxed);
+    ALLOCATED.store(0, Relaxed);
+    ALLOCATIONS.store(0, Relaxed);
+    let output = f();
+    let usage = MemoryUsage {
+        peak_bytes: PEAK.load(Relaxed).max(0) as usize,
+        allocated_bytes: ALLOCATED.load(Relaxed),
+        allocations: ALLOCATIONS.load(Relaxed),
+    };
+    (output, Some(usage))
+}
+
+#[test]
+fn test_format_bytes() {
+    assert_eq!(Bytes(512).to_string(), "512 B");
+    assert_eq!(Bytes(1536).to_string(), "1.5 KiB");
+    assert_eq!(Bytes(3 << 20).to_string(), "3.0 MiB");
+    assert_eq!(Bytes(5 << 30).to_string(), "5.0 GiB");
+    let usage = MemoryUsage {
+        peak_bytes: 2048,
+        allocated_bytes: 4096,
+        allocations: 3,
+    };
+    assert_eq!(
+        usage.to_string(),
+        "peak 2.0 KiB, 3 allocations of 4.0 KiB in total"
+    );
+}
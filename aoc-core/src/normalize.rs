@@ -0,0 +1,143 @@
+//! Cleaning up inputs that went through a browser or editor before they reach the parsers.
+//!
+//! Spaces at the end of a line are kept, because some puzzles (e.g. the crate diagram of 2022 day 5)
+//! depend on them.
+
+use std::fmt;
+
+const BOM: &[u8] = b"\xEF\xBB\xBF";
+
+/// What [`normalize`] changed about an input.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Changes {
+    pub removed_bom: bool,
+    pub crlf_line_endings: usize,
+    pub trailing_blank_lines: usize,
+    /// Whitespace after the last line, not counting the newlines of blank lines.
+    pub trailing_whitespace: usize,
+    pub added_final_newline: bool,
+}
+
+impl Changes {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+impl fmt::Display for Changes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut changes = Vec::new();
+        if self.removed_bom {
+            changes.push("removed byte order mark".to_owned());
+        }
+        if self.crlf_line_endings > 0 {
+            changes.push(format!(
+                "converted {} CRLF line endings",
+                self.crlf_line_endings
+            ));
+        }
+        if self.trailing_blank_lines > 0 {
+            changes.push(format!(
+                "removed {} trailing blank lines",
+                self.trailing_blank_lines
+            ));
+        }
+        if self.trailing_whitespace > 0 {
+            changes.push(format!(
+                "removed {} whitespace characters after the last line",
+                self.trailing_whitespace
+            ));
+        }
+        if self.added_final_newline {
+            changes.push("added final newline".to_owned());
+        }
+        if changes.is_empty() {
+            write!(f, "unchanged")
+        } else {
+            write!(f, "{}", changes.join(", "))
+        }
+    }
+}
+
+/// Remove a byte order mark, convert CRLF to LF and make the input end in exactly one newline.
+pub fn normalize(input: &[u8]) -> (Vec<u8>, Changes) {
+    let mut changes = Changes::default();
+
+    let input = match input.strip_prefix(BOM) {
+        Some(rest) => {
+            changes.removed_bom = true;
+            rest
+        }
+        None => input,
+    };
+
+    let mut output = Vec::with_capacity(input.len() + 1);
+    let mut bytes = input.iter().copied().peekable();
+    while let Some(ch) = bytes.next() {
+        if ch == b'\r' && bytes.peek() == Some(&b'\n') {
+            changes.crlf_line_endings += 1;
+        } else {
+            output.push(ch);
+        }
+    }
+
+    // Inputs consisting only of whitespace are left alone, there is no line to end.
+    if let Some(last) = output.iter().rposition(|ch| !ch.is_ascii_whitespace()) {
+        match output[last..].iter().position(|&ch| ch == b'\n') {
+            Some(newline) => {
+                let content_end = last + newline;
+                let trailing = &output[content_end + 1..];
+                changes.trailing_blank_lines = trailing.iter().filter(|&&ch| ch == b'\n').count();
+                changes.trailing_whitespace = trailing.len() - changes.trailing_blank_lines;
+                output.truncate(content_end + 1);
+            }
+            None => {
+                changes.added_final_newline = true;
+                output.push(b'\n');
+            }
+        }
+    }
+
+    (output, changes)
+}
+
+#[test]
+fn test_normalize() {
+    assert_eq!(
+        normalize(b"1\n2\n"),
+        (b"1\n2\n".to_vec(), Changes::default())
+    );
+
+    let (output, changes) = normalize(b"\xEF\xBB\xBF1\r\n2\r\n\r\n  \n");
+    assert_eq!(output, b"1\n2\n");
+    assert_eq!(
+        changes,
+        Changes {
+            removed_bom: true,
+            crlf_line_endings: 3,
+            trailing_blank_lines: 2,
+            trailing_whitespace: 2,
+            added_final_newline: false,
+        }
+    );
+    assert_eq!(
+        changes.to_string(),
+        "removed byte order mark, converted 3 CRLF line endings, removed 2 trailing blank lines, \
+         removed 2 whitespace characters after the last line"
+    );
+
+    // Whitespace after the final newline is removed even without a blank line.
+    let (output, changes) = normalize(b"abc\n \t ");
+    assert_eq!(output, b"abc\n");
+    assert_eq!(
+        changes.to_string(),
+        "removed 3 whitespace characters after the last line"
+    );
+
+    // Trailing spaces within the input are significant for some puzzles.
+    let (output, changes) = normalize(b"    [D]    \n1 2 3");
+    assert_eq!(output, b"    [D]    \n1 2 3\n");
+    assert_eq!(changes.to_string(), "added final newline");
+
+    assert_eq!(normalize(b""), (Vec::new(), Changes::default()));
+}
@@ -0,0 +1,134 @@
+//! Reading the letters that some puzzles draw as a picture, like the CRT of 2022 day 10 or the
+//! folded paper of 2021 day 13.
+//!
+//! Two fonts are known: the common one with letters that are 6 pixels high and about 4 wide, and
+//! a larger one with letters 10 pixels high and 6 wide. Letters are separated by at least one
+//! column of dark pixels.
+
+use crate::answer::Image;
+
+type Glyph = (char, &'static [&'static str]);
+
+const FONT_6: &[Glyph] = &[
+    ('A', &[".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', &["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', &[".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', &["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', &["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', &[".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', &["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', &["###", ".#.", ".#.", ".#.", ".#.", "###"]),
+    ('J', &["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', &["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', &["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', &[".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', &["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', &["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', &[".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', &["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', &["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', &["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+#[rustfmt::skip]
+const FONT_10: &[Glyph] = &[
+    ('A', &["..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#"]),
+    ('B', &["#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#", "#....#", "#####."]),
+    ('C', &[".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#....#", ".####."]),
+    ('E', &["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "######"]),
+    ('F', &["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+    ('G', &[".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#", "#...##", ".###.#"]),
+    ('H', &["#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#", "#....#"]),
+    ('J', &["...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.", "#...#.", ".###.."]),
+    ('K', &["#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..", "#...#.", "#....#"]),
+    ('L', &["#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "######"]),
+    ('N', &["#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##", "#...##", "#....#"]),
+    ('P', &["#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+    ('R', &["#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.", "#....#", "#....#"]),
+    ('X', &["#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.", "#....#", "#....#"]),
+    ('Z', &["######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....", "#.....", "######"]),
+];
+
+/// The letters shown in the picture, or `None` if it contains anything but known letters.
+pub fn recognize(image: &Image) -> Option<String> {
+    let lit_row = |y| (0..image.width()).any(|x| image.get(x, y));
+    let lit_column = |x| (0..image.height()).any(|y| image.get(x, y));
+
+    let top = (0..image.height()).find(|&y| lit_row(y))?;
+    let bottom = (0..image.height()).rev().find(|&y| lit_row(y))? + 1;
+    let font = match bottom - top {
+        6 => FONT_6,
+        10 => FONT_10,
+        _ => return None,
+    };
+
+    let mut letters = String::new();
+    let mut x = 0;
+    while x < image.width() {
+        if !lit_column(x) {
+            x += 1;
+            continue;
+        }
+        let start = x;
+        while x < image.width() && lit_column(x) {
+            x += 1;
+        }
+        let glyph = (top..bottom)
+            .map(|y| {
+                (start..x)
+                    .map(|x| if image.get(x, y) { '#' } else { '.' })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>();
+        let (letter, _) = font.iter().find(|(_, rows)| glyph == *rows)?;
+        letters.push(*letter);
+    }
+    Some(letters)
+}
+
+#[test]
+fn test_recognize() {
+    let image = Image::parse(
+        "####.####.####..##..#..#...##..##..###..\n\
+         #.......#.#....#..#.#..#....#.#..#.#..#.\n\
+         ###....#..###..#....####....#.#..#.###..\n\
+         #.....#...#....#....#..#....#.####.#..#.\n\
+         #....#....#....#..#.#..#.#..#.#..#.#..#.\n\
+         ####.####.#.....##..#..#..##..#..#.###..",
+    )
+    .unwrap();
+    assert_eq!(recognize(&image).as_deref(), Some("EZFCHJAB"));
+
+    // Without the trailing empty column and with an empty row below.
+    let image = Image::parse(
+        "####.###..#....#..#.###..###..####.#..#\n\
+         #....#..#.#....#..#.#..#.#..#.#....#..#\n\
+         ###..###..#....#..#.###..#..#.###..####\n\
+         #....#..#.#....#..#.#..#.###..#....#..#\n\
+         #....#..#.#....#..#.#..#.#.#..#....#..#\n\
+         ####.###..####..##..###..#..#.#....#..#\n\
+         .......................................",
+    )
+    .unwrap();
+    assert_eq!(recognize(&image).as_deref(), Some("EBLUBRFH"));
+
+    let image = Image::parse(
+        "#...#.###.\n\
+         #...#..#..\n\
+         .#.#...#..\n\
+         ..#....#..\n\
+         ..#....#..\n\
+         ..#...###.",
+    )
+    .unwrap();
+    assert_eq!(recognize(&image).as_deref(), Some("YI"));
+
+    let rows = FONT_10.iter().find(|(letter, _)| *letter == 'X').unwrap().1;
+    let image = Image::parse(&rows.join("\n")).unwrap();
+    assert_eq!(recognize(&image).as_deref(), Some("X"));
+
+    let square = Image::parse("#####\n#...#\n#...#\n#...#\n#####").unwrap();
+    assert_eq!(recognize(&square), None);
+    let unknown = Image::parse("#..#\n#..#\n####\n#..#\n#..#\n###.").unwrap();
+    assert_eq!(recognize(&unknown), None);
+}
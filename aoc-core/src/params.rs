@@ -0,0 +1,119 @@
+//! Puzzle parameters that differ between the example and the real input, like the row to look at
+//! in 2022 day 15.
+//!
+//! A day declares its parameters with their defaults for the real input. They can be overridden
+//! for a specific input file with `param.<name>: <value>` lines in its answers file, and on the
+//! command line with `--param <name>=<value>`.
+
+use std::{fmt, str::FromStr};
+
+use anyhow::Context;
+
+/// The declaration of an integer parameter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Param {
+    pub name: &'static str,
+    pub default: i64,
+}
+
+impl Param {
+    pub const fn new(name: &'static str, default: i64) -> Self {
+        Self { name, default }
+    }
+}
+
+/// The values of all parameters of a day.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params {
+    values: Vec<(&'static str, i64)>,
+}
+
+impl Params {
+    pub fn defaults(declared: &[Param]) -> Self {
+        Self {
+            values: declared
+                .iter()
+                .map(|param| (param.name, param.default))
+                .collect(),
+        }
+    }
+
+    /// Set a declared parameter, returning whether there is a parameter of that name.
+    pub fn set(&mut self, name: &str, value: i64) -> bool {
+        match self.values.iter_mut().find(|(n, _)| *n == name) {
+            Some((_, current)) => {
+                *current = value;
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn get<T: TryFrom<i64>>(&self, name: &str) -> anyhow::Result<T> {
+        let value = self
+            .values
+            .iter()
+            .find(|(n, _)| *n == name)
+            .map(|(_, value)| *value)
+            .with_context(|| format!("no parameter named {}", name))?;
+        T::try_from(value)
+            .ok()
+            .with_context(|| format!("parameter {} is out of range: {}", name, value))
+    }
+}
+
+impl fmt::Display for Params {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, (name, value)) in self.values.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}={}", name, value)?;
+        }
+        Ok(())
+    }
+}
+
+/// A `<name>=<value>` parameter given on the command line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParamOverride {
+    pub name: String,
+    pub value: i64,
+}
+
+impl FromStr for ParamOverride {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, value) = s
+            .split_once('=')
+            .context("parameters must be given as <name>=<value>")?;
+        let value = value
+            .trim()
+            .parse()
+            .with_context(|| format!("parameter {} must be an integer", name))?;
+        Ok(Self {
+            name: name.trim().to_owned(),
+            value,
+        })
+    }
+}
+
+#[test]
+fn test_params() {
+    const DECLARED: &[Param] = &[Param::new("row", 2000000), Param::new("max_coord", 4000000)];
+
+    let mut params = Params::defaults(DECLARED);
+    assert_eq!(params.get::<i32>("row").unwrap(), 2000000);
+    assert!(params.set("row", 10));
+    assert!(!params.set("rows", 10));
+    assert_eq!(params.get::<i32>("row").unwrap(), 10);
+    assert!(params.get::<u8>("max_coord").is_err());
+    assert!(params.get::<i32>("column").is_err());
+    assert_eq!(params.to_string(), "row=10, max_coord=4000000");
+
+    let param: ParamOverride = "row=10".parse().unwrap();
+    assert_eq!((param.name.as_str(), param.value), ("row", 10));
+    assert!("row".parse::<ParamOverride>().is_err());
+    assert!("row=ten".parse::<ParamOverride>().is_err());
+}
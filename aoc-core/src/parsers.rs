@@ -0,0 +1,272 @@
+//! Various nom parsers that are often useful
+
+use std::{fmt, ops::RangeInclusive};
+
+use nom::{
+    bytes::complete::tag,
+    character::complete::digit1,
+    combinator::{fail, map},
+    error::{ErrorKind, VerboseError, VerboseErrorKind},
+    IResult,
+};
+
+macro_rules! parse_int {
+    ($name:ident) => {
+        pub fn $name(input: &[u8]) -> IResult<&[u8], $name> {
+            map(digit1, |digits: &[u8]| {
+                digits
+                    .iter()
+                    .map(|d| d - b'0')
+                    .fold(0, |acc, d| acc * 10 + d as $name)
+            })(input)
+        }
+    };
+}
+
+parse_int!(i32);
+parse_int!(i64);
+
+parse_int!(u8);
+parse_int!(u32);
+parse_int!(u64);
+
+pub fn parse<'a, O, E: LocateError<'a>>(
+    mut parser: impl FnMut(&'a [u8]) -> IResult<&'a [u8], O, E>,
+    input: &'a [u8],
+) -> anyhow::Result<O> {
+    let (rest, output) = parser(input).map_err(|err| match err {
+        nom::Err::Incomplete(needed) => {
+            let message = format!("needs more input: {:?}", needed);
+            ParseError::new(input, &input[input.len()..], message, None, Vec::new())
+        }
+        nom::Err::Error(inner) => inner.locate(input, "error"),
+        nom::Err::Failure(inner) => inner.locate(input, "failure"),
+    })?;
+    if rest.is_empty() {
+        Ok(output)
+    } else {
+        let message = "parser did not consume whole input".to_owned();
+        Err(ParseError::new(input, rest, message, None, Vec::new()).into())
+    }
+}
+
+/// A parse error pointing at the line and column where parsing failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub message: String,
+    pub kind: Option<ErrorKind>,
+    /// The labels added with `nom::error::context`, outermost first.
+    pub contexts: Vec<&'static str>,
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in bytes.
+    pub column: usize,
+    /// The offending line, without its line break.
+    pub source_line: Vec<u8>,
+}
+
+impl ParseError {
+    /// Create an error for a failure at `rest`, which must be a suffix of `input`.
+    pub fn new(
+        input: &[u8],
+        rest: &[u8],
+        message: String,
+        kind: Option<ErrorKind>,
+        contexts: Vec<&'static str>,
+    ) -> Self {
+        let offset = input.len() - rest.len();
+        let line_start = input[..offset]
+            .iter()
+            .rposition(|&ch| ch == b'\n')
+            .map_or(0, |pos| pos + 1);
+        let line_end = input[offset..]
+            .iter()
+            .position(|&ch| ch == b'\n')
+            .map_or(input.len(), |pos| offset + pos);
+        Self {
+            message,
+            kind,
+            contexts,
+            line: input[..line_start]
+                .iter()
+                .filter(|&&ch| ch == b'\n')
+                .count()
+                + 1,
+            column: offset - line_start + 1,
+            source_line: input[line_start..line_end].to_vec(),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} at line {}, column {}",
+            self.message, self.line, self.column
+        )?;
+        if let Some(kind) = self.kind {
+            write!(f, " ({:?})", kind)?;
+        }
+        if !self.contexts.is_empty() {
+            write!(f, " in {}", self.contexts.join(" > "))?;
+        }
+
+        // Control characters and non-ASCII bytes are escaped so that e.g. a stray `\r` is visible.
+        let gutter = self.line.to_string();
+        let mut rendered = String::new();
+        let mut caret = 0;
+        for (index, &ch) in self.source_line.iter().enumerate() {
+            if index + 1 == self.column {
+                caret = rendered.chars().count();
+            }
+            if ch.is_ascii_graphic() || ch == b' ' {
+                rendered.push(ch as char);
+            } else {
+                rendered.extend(std::ascii::escape_default(ch).map(char::from));
+            }
+        }
+        if self.column > self.source_line.len() {
+            caret = rendered.chars().count();
+        }
+        writeln!(f)?;
+        writeln!(f, "{} | {}", gutter, rendered)?;
+        write!(f, "{} | {}^", " ".repeat(gutter.len()), " ".repeat(caret))
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Nom error types that can be turned into a [`ParseError`].
+pub trait LocateError<'a> {
+    fn locate(self, input: &'a [u8], severity: &str) -> ParseError;
+}
+
+impl<'a> LocateError<'a> for nom::error::Error<&'a [u8]> {
+    fn locate(self, input: &'a [u8], severity: &str) -> ParseError {
+        ParseError::new(
+            input,
+            self.input,
+            severity.to_owned(),
+            Some(self.code),
+            Vec::new(),
+        )
+    }
+}
+
+impl<'a> LocateError<'a> for VerboseError<&'a [u8]> {
+    fn locate(self, input: &'a [u8], severity: &str) -> ParseError {
+        let mut message = severity.to_owned();
+        let mut kind = None;
+        let mut contexts = Vec::new();
+        let mut rest = &input[input.len()..];
+        // The innermost error comes first.
+        for (index, (position, error)) in self.errors.into_iter().enumerate() {
+            if index == 0 {
+                rest = position;
+            }
+            match error {
+                VerboseErrorKind::Context(label) => contexts.push(label),
+                VerboseErrorKind::Char(ch) if index == 0 => {
+                    message = format!("{}: expected {:?}", severity, ch)
+                }
+                VerboseErrorKind::Nom(code) if kind.is_none() => kind = Some(code),
+                _ => {}
+            }
+        }
+        contexts.reverse();
+        ParseError::new(input, rest, message, kind, contexts)
+    }
+}
+
+pub fn asciichar(input: &[u8]) -> IResult<&[u8], char> {
+    match input {
+        [x, rest @ ..] if *x < 128 => Ok((rest, *x as char)),
+        _ => fail(input),
+    }
+}
+
+pub fn byte(input: &[u8]) -> IResult<&[u8], u8> {
+    match input {
+        [x, rest @ ..] => Ok((rest, *x)),
+        _ => fail(input),
+    }
+}
+
+pub fn byte_range(valid: RangeInclusive<u8>) -> impl Fn(&[u8]) -> IResult<&[u8], u8> {
+    move |input| match input {
+        [x, rest @ ..] if valid.contains(x) => Ok((rest, *x)),
+        _ => fail(input),
+    }
+}
+
+pub fn newline(input: &[u8]) -> IResult<&[u8], ()> {
+    map(tag(b"\n"), |_| ())(input)
+}
+
+pub fn newline_or_eof(input: &[u8]) -> IResult<&[u8], ()> {
+    match input {
+        [b'\n', rest @ ..] => Ok((rest, ())),
+        [] => Ok((b"", ())),
+        _ => fail(input),
+    }
+}
+
+#[test]
+fn test_asciichar() {
+    assert_eq!(asciichar(b"Foo"), Ok((b"oo".as_slice(), 'F')));
+    assert!(asciichar(b"\xC4oo").is_err());
+    assert!(asciichar(b"").is_err());
+}
+
+#[test]
+fn test_parse_error_location() {
+    use nom::{
+        error::context,
+        multi::{count, many1},
+        sequence::terminated,
+    };
+
+    let input = b"12\n34\r\n56\n";
+    let err = parse(count(terminated(u32, newline), 3), input).unwrap_err();
+    let err = err.downcast::<ParseError>().unwrap();
+    assert_eq!((err.line, err.column), (2, 3));
+    assert_eq!(err.kind, Some(ErrorKind::Tag));
+    assert_eq!(err.source_line, b"34\r");
+    assert_eq!(
+        err.to_string(),
+        "error at line 2, column 3 (Tag)\n2 | 34\\r\n  |   ^"
+    );
+
+    let err = parse(many1(terminated(u32, newline)), b"12\nx".as_slice())
+        .unwrap_err()
+        .downcast::<ParseError>()
+        .unwrap();
+    assert_eq!((err.line, err.column), (2, 1));
+    assert_eq!(err.kind, None);
+
+    let err = parse::<_, VerboseError<&[u8]>>(
+        context("numbers", terminated(u32_verbose, newline_verbose)),
+        b"12 \n",
+    )
+    .unwrap_err()
+    .downcast::<ParseError>()
+    .unwrap();
+    assert_eq!((err.line, err.column), (1, 3));
+    assert_eq!(err.kind, Some(ErrorKind::Tag));
+    assert_eq!(err.contexts, ["numbers"]);
+    assert_eq!(
+        err.to_string(),
+        "error at line 1, column 3 (Tag) in numbers\n1 | 12 \n  |   ^"
+    );
+}
+
+#[cfg(test)]
+fn u32_verbose(input: &[u8]) -> IResult<&[u8], u32, VerboseError<&[u8]>> {
+    nom::character::complete::u32(input)
+}
+
+#[cfg(test)]
+fn newline_verbose(input: &[u8]) -> IResult<&[u8], &[u8], VerboseError<&[u8]>> {
+    tag(b"\n")(input)
+}
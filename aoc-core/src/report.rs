@@ -0,0 +1,457 @@
+//! Output formats for the results of a run.
+
+use std::{fmt, io::Write, str::FromStr, time::Duration};
+
+use serde::Serialize;
+
+use crate::{answer::Answer, answers::Verdict, memory::MemoryUsage, runner::Part, stats::Timings};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+    Markdown,
+}
+
+#[derive(Debug, Clone)]
+pub struct NoFormat;
+
+impl fmt::Display for NoFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "valid formats are text, json, csv and markdown")
+    }
+}
+
+impl FromStr for Format {
+    type Err = NoFormat;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            "markdown" | "md" => Ok(Format::Markdown),
+            _ => Err(NoFormat),
+        }
+    }
+}
+
+/// The result of running one part of one day.
+#[derive(Debug, Clone)]
+pub struct PartResult {
+    pub year: u32,
+    pub day: u32,
+    pub part: Part,
+    pub outcome: Outcome,
+    pub timings: Timings,
+    /// The memory used by the last run, if it was counted.
+    pub memory: Option<MemoryUsage>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Solved {
+        answer: Answer,
+        verdict: Verdict,
+    },
+    /// The solution returned an error.
+    Failed(String),
+    /// The solution panicked with the given message.
+    Panicked(String),
+    /// A run of the solution took longer than the timeout.
+    TimedOut(Duration),
+    /// The day is a [`crate::Day::unsolved`] placeholder.
+    Unsolved,
+    /// The input does not exist, with a hint on how to get it.
+    Missing(String),
+}
+
+impl Outcome {
+    pub fn answer(&self) -> Option<&Answer> {
+        match self {
+            Outcome::Solved { answer, .. } => Some(answer),
+            _ => None,
+        }
+    }
+
+    pub fn error(&self) -> Option<String> {
+        match self {
+            Outcome::Solved { .. } | Outcome::Unsolved => None,
+            Outcome::Failed(message) | Outcome::Missing(message) => Some(message.clone()),
+            Outcome::Panicked(message) => Some(format!("panicked: {}", message)),
+            Outcome::TimedOut(timeout) => {
+                Some(format!("timed out after {:.3} ms", millis(*timeout)))
+            }
+        }
+    }
+
+    pub fn status(&self) -> &'static str {
+        match self {
+            Outcome::Solved { verdict, .. } => match verdict {
+                Verdict::Pass => "PASS",
+                Verdict::Fail => "FAIL",
+                Verdict::Unknown => "UNKNOWN",
+            },
+            Outcome::Failed(_) => "ERROR",
+            Outcome::Panicked(_) => "PANIC",
+            Outcome::TimedOut(_) => "TIMEOUT",
+            Outcome::Unsolved => "UNSOLVED",
+            Outcome::Missing(_) => "MISSING",
+        }
+    }
+}
+
+/// A flat view of a [`PartResult`], as written by the machine-readable formats.
+#[derive(Serialize)]
+struct Record<'a> {
+    year: u32,
+    day: u32,
+    part: u8,
+    answer: Option<&'a Answer>,
+    status: &'static str,
+    error: Option<String>,
+    runs: usize,
+    min_ms: f64,
+    median_ms: f64,
+    mean_ms: f64,
+    p95_ms: f64,
+    max_ms: f64,
+    stddev_ms: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    peak_bytes: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allocated_bytes: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allocations: Option<usize>,
+}
+
+impl<'a> From<&'a PartResult> for Record<'a> {
+    fn from(result: &'a PartResult) -> Self {
+        Record {
+            year: result.year,
+            day: result.day,
+            part: result.part.number(),
+            answer: result.outcome.answer(),
+            status: result.outcome.status(),
+            error: result.outcome.error(),
+            runs: result.timings.runs(),
+            min_ms: millis(result.timings.min()),
+            median_ms: millis(result.timings.median()),
+            mean_ms: millis(result.timings.mean()),
+            p95_ms: millis(result.timings.percentile(95.0)),
+            max_ms: millis(result.timings.max()),
+            stddev_ms: millis(result.timings.stddev()),
+            peak_bytes: result.memory.map(|memory| memory.peak_bytes),
+            allocated_bytes: result.memory.map(|memory| memory.allocated_bytes),
+            allocations: result.memory.map(|memory| memory.allocations),
+        }
+    }
+}
+
+pub fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+/// Write the results in the given format. In the text format, both parts of a day are written on
+/// a single line, with all answers but integers quoted, and the statistics of repeated runs follow
+/// the status of the parts.
+pub fn write_results(
+    out: &mut impl Write,
+    format: Format,
+    results: &[PartResult],
+) -> std::io::Result<()> {
+    match format {
+        Format::Text => write_text(out, results),
+        Format::Json => {
+            let records = results.iter().map(Record::from).collect::<Vec<_>>();
+            serde_json::to_writer_pretty(&mut *out, &records)?;
+            writeln!(out)
+        }
+        Format::Csv => write_csv(out, results),
+        Format::Markdown => write_markdown(out, results),
+    }
+}
+
+fn write_text(out: &mut impl Write, results: &[PartResult]) -> std::io::Result<()> {
+    let with_statistics = results.iter().any(|result| result.timings.runs() > 1);
+    let with_memory = results.iter().any(|result| result.memory.is_some());
+    for day in chunk_by_day(results) {
+        write!(out, "{}\t{}", day[0].year, day[0].day)?;
+        for result in day {
+            match (result.outcome.answer(), result.outcome.error()) {
+                (Some(answer @ (Answer::Integer(_) | Answer::BigInteger(_))), _) => {
+                    write!(out, "\t{}", answer)?
+                }
+                (Some(answer), _) => write!(out, "\t{:?}", answer.to_string())?,
+                (None, message) => write!(out, "\t{:?}", message.unwrap_or_default())?,
+            }
+        }
+        for result in day {
+            write!(out, "\t{:.3}", millis(result.timings.mean()))?;
+        }
+        for result in day {
+            write!(out, "\t{}", result.outcome.status())?;
+        }
+        if with_statistics {
+            let statistics: [fn(&Timings) -> Duration; 5] = [
+                Timings::min,
+                Timings::median,
+                |timings| timings.percentile(95.0),
+                Timings::max,
+                Timings::stddev,
+            ];
+            for statistic in statistics {
+                for result in day {
+                    write!(out, "\t{:.3}", millis(statistic(&result.timings)))?;
+                }
+            }
+        }
+        if with_memory {
+            // Parts that did not run have no memory usage, which is not the same as none.
+            let fields: [fn(&MemoryUsage) -> usize; 3] = [
+                |memory| memory.peak_bytes,
+                |memory| memory.allocated_bytes,
+                |memory| memory.allocations,
+            ];
+            for field in fields {
+                for result in day {
+                    match &result.memory {
+                        Some(memory) => write!(out, "\t{}", field(memory))?,
+                        None => write!(out, "\t-")?,
+                    }
+                }
+            }
+        }
+        writeln!(out)?;
+    }
+    Ok(())
+}
+
+const COLUMNS: [&str; 13] = [
+    "year",
+    "day",
+    "part",
+    "answer",
+    "status",
+    "error",
+    "runs",
+    "min_ms",
+    "median_ms",
+    "mean_ms",
+    "p95_ms",
+    "max_ms",
+    "stddev_ms",
+];
+
+/// Additional columns when the memory was counted.
+const MEMORY_COLUMNS: [&str; 3] = ["peak_bytes", "allocated_bytes", "allocations"];
+
+/// The memory columns of a record, as strings that are empty if the memory was not counted.
+fn memory_fields(record: &Record) -> [String; 3] {
+    [
+        record.peak_bytes,
+        record.allocated_bytes,
+        record.allocations,
+    ]
+    .map(|value| value.map(|value| value.to_string()).unwrap_or_default())
+}
+
+fn columns(results: &[PartResult]) -> Vec<&'static str> {
+    let mut columns = COLUMNS.to_vec();
+    if results.iter().any(|result| result.memory.is_some()) {
+        columns.extend(MEMORY_COLUMNS);
+    }
+    columns
+}
+
+fn write_csv(out: &mut impl Write, results: &[PartResult]) -> std::io::Result<()> {
+    let columns = columns(results);
+    writeln!(out, "{}", columns.join(","))?;
+    for result in results {
+        let record = Record::from(result);
+        write!(
+            out,
+            "{},{},{},{},{},{},{},{:.3},{:.3},{:.3},{:.3},{:.3},{:.3}",
+            record.year,
+            record.day,
+            record.part,
+            csv_field(&record.answer.map(Answer::to_string).unwrap_or_default()),
+            record.status,
+            csv_field(record.error.as_deref().unwrap_or_default()),
+            record.runs,
+            record.min_ms,
+            record.median_ms,
+            record.mean_ms,
+            record.p95_ms,
+            record.max_ms,
+            record.stddev_ms,
+        )?;
+        if columns.len() > COLUMNS.len() {
+            write!(out, ",{}", memory_fields(&record).join(","))?;
+        }
+        writeln!(out)?;
+    }
+    Ok(())
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_owned()
+    }
+}
+
+fn write_markdown(out: &mut impl Write, results: &[PartResult]) -> std::io::Result<()> {
+    let columns = columns(results);
+    writeln!(out, "| {} |", columns.join(" | "))?;
+    writeln!(out, "|{}", "---|".repeat(columns.len()))?;
+    for result in results {
+        let record = Record::from(result);
+        write!(
+            out,
+            "| {} | {} | {} | {} | {} | {} | {} | {:.3} | {:.3} | {:.3} | {:.3} | {:.3} | {:.3} |",
+            record.year,
+            record.day,
+            record.part,
+            markdown_cell(&record.answer.map(Answer::to_string).unwrap_or_default()),
+            record.status,
+            markdown_cell(record.error.as_deref().unwrap_or_default()),
+            record.runs,
+            record.min_ms,
+            record.median_ms,
+            record.mean_ms,
+            record.p95_ms,
+            record.max_ms,
+            record.stddev_ms,
+        )?;
+        if columns.len() > COLUMNS.len() {
+            write!(out, " {} |", memory_fields(&record).join(" | "))?;
+        }
+        writeln!(out)?;
+    }
+    Ok(())
+}
+
+fn markdown_cell(value: &str) -> String {
+    if value.is_empty() {
+        String::new()
+    } else {
+        let escaped = value.replace('|', "\\|").replace('\n', "<br>");
+        if value.contains('\n') {
+            format!("<pre>{}</pre>", escaped)
+        } else {
+            format!("`{}`", escaped)
+        }
+    }
+}
+
+/// Split the results into runs of consecutive results for the same day.
+fn chunk_by_day(results: &[PartResult]) -> Vec<&[PartResult]> {
+    let mut chunks = Vec::new();
+    let mut rest = results;
+    while let Some(first) = rest.first() {
+        let len = rest
+            .iter()
+            .take_while(|r| (r.year, r.day) == (first.year, first.day))
+            .count();
+        let (chunk, tail) = rest.split_at(len);
+        chunks.push(chunk);
+        rest = tail;
+    }
+    chunks
+}
+
+#[cfg(test)]
+fn example_results() -> Vec<PartResult> {
+    let timings = Timings::from_samples(vec![Duration::from_millis(1), Duration::from_millis(2)]);
+    vec![
+        PartResult {
+            year: 2022,
+            day: 10,
+            part: Part::One,
+            outcome: Outcome::Solved {
+                answer: Answer::Integer(13180),
+                verdict: Verdict::Pass,
+            },
+            timings: timings.clone(),
+            memory: None,
+        },
+        PartResult {
+            year: 2022,
+            day: 10,
+            part: Part::Two,
+            outcome: Outcome::Failed("no solution, \"really\"".to_owned()),
+            timings,
+            memory: None,
+        },
+    ]
+}
+
+#[test]
+fn test_write_results() {
+    let results = example_results();
+    let render = |format| {
+        let mut out = Vec::new();
+        write_results(&mut out, format, &results).unwrap();
+        String::from_utf8(out).unwrap()
+    };
+
+    assert_eq!(
+        render(Format::Text),
+        "2022\t10\t13180\t\"no solution, \\\"really\\\"\"\t1.500\t1.500\tPASS\tERROR\
+         \t1.000\t1.000\t1.500\t1.500\t2.000\t2.000\t2.000\t2.000\t0.707\t0.707\n"
+    );
+    assert_eq!(
+        render(Format::Csv),
+        "year,day,part,answer,status,error,runs,min_ms,median_ms,mean_ms,p95_ms,max_ms,stddev_ms\n\
+         2022,10,1,13180,PASS,,2,1.000,1.500,1.500,2.000,2.000,0.707\n\
+         2022,10,2,,ERROR,\"no solution, \"\"really\"\"\",2,1.000,1.500,1.500,2.000,2.000,0.707\n"
+    );
+
+    let json: serde_json::Value = serde_json::from_str(&render(Format::Json)).unwrap();
+    assert_eq!(json[0]["answer"], 13180);
+    assert_eq!(json[1]["answer"], serde_json::Value::Null);
+    assert_eq!(json[1]["error"], "no solution, \"really\"");
+    assert_eq!(json[1]["status"], "ERROR");
+    assert_eq!(json[1]["median_ms"], 1.5);
+    assert!(json[1].get("peak_bytes").is_none());
+
+    let mut results = example_results();
+    results[0].memory = Some(MemoryUsage {
+        peak_bytes: 1024,
+        allocated_bytes: 4096,
+        allocations: 3,
+    });
+    let render = |format| {
+        let mut out = Vec::new();
+        write_results(&mut out, format, &results).unwrap();
+        String::from_utf8(out).unwrap()
+    };
+    assert_eq!(
+        render(Format::Text),
+        "2022\t10\t13180\t\"no solution, \\\"really\\\"\"\t1.500\t1.500\tPASS\tERROR\
+         \t1.000\t1.000\t1.500\t1.500\t2.000\t2.000\t2.000\t2.000\t0.707\t0.707\
+         \t1024\t-\t4096\t-\t3\t-\n"
+    );
+    let csv = render(Format::Csv);
+    assert!(csv.starts_with("year,day,part,"));
+    assert!(csv.contains(",stddev_ms,peak_bytes,allocated_bytes,allocations\n"));
+    assert!(csv.contains(",0.707,1024,4096,3\n"));
+    assert!(csv.ends_with(",0.707,,,\n"));
+    let json: serde_json::Value = serde_json::from_str(&render(Format::Json)).unwrap();
+    assert_eq!(json[0]["allocations"], 3);
+
+    // Without repetitions, there is nothing to summarize beyond the time of the single run.
+    let mut results = example_results();
+    for result in &mut results {
+        result.timings = Timings::from_samples(vec![Duration::from_millis(2)]);
+    }
+    let mut out = Vec::new();
+    write_results(&mut out, Format::Text, &results).unwrap();
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        "2022\t10\t13180\t\"no solution, \\\"really\\\"\"\t2.000\t2.000\tPASS\tERROR\n"
+    );
+}
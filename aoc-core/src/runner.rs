@@ -0,0 +1,1177 @@
+use std::{
+    any::Any,
+    cell::Cell,
+    fmt,
+    io::Read,
+    panic,
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc, Arc,
+    },
+    time::{Duration, Instant},
+};
+
+use anyhow::Context;
+use structopt::StructOpt;
+
+use crate::{
+    answer::Answer,
+    answers::{Answers, Verdict},
+    baseline::{self, Baseline},
+    config::Config,
+    memory::{self, MemoryUsage},
+    normalize,
+    params::{Param, ParamOverride, Params},
+    report::{self, millis, Format, Outcome, PartResult},
+    scaffold,
+    stats::Timings,
+    submissions::{self, Response, Submission, SubmissionLog},
+    website::{self, Fetched},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+#[derive(Debug, Clone)]
+pub struct NoPart;
+
+impl fmt::Display for NoPart {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "only part 1 and 2 are valid")
+    }
+}
+
+impl Part {
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = NoPart;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(NoPart),
+        }
+    }
+}
+
+#[derive(Debug, StructOpt)]
+#[structopt(
+    name = "advent-of-code",
+    about = "Solutions for Advent of Code puzzles.",
+    setting = structopt::clap::AppSettings::SubcommandsNegateReqs
+)]
+pub struct AocOpt {
+    #[structopt(subcommand)]
+    command: Option<Command>,
+
+    /// Only run the solutions of this year. Required with `--day` when there are several years.
+    #[structopt(short, long)]
+    year: Option<u32>,
+
+    #[structopt(short, long, required_unless_one(&["all"]))]
+    day: Option<u32>,
+
+    #[structopt(short, long, default_value("1"))]
+    part: Part,
+
+    #[structopt(short, long, conflicts_with_all(&["day", "part"]))]
+    all: bool,
+
+    /// Number of threads to run the days on with `--all`. 0 uses one thread per CPU.
+    #[structopt(short, long, default_value("1"))]
+    jobs: usize,
+
+    /// Repeat the computation many times for easier flamegraphing and timing statistics.
+    #[structopt(short, long, default_value("1"))]
+    repeat: usize,
+
+    /// Override input file (or directory with `--all`). The input directory can also be set with
+    /// the `AOC_INPUT_DIR` environment variable.
+    #[structopt(short, long, parse(from_os_str))]
+    input: Option<PathBuf>,
+
+    /// Run with example input
+    #[structopt(short, long, conflicts_with_all(&["input"]))]
+    example: bool,
+
+    /// Pass the inputs to the solutions exactly as they are on disk, instead of removing a byte
+    /// order mark, converting CRLF line endings and making them end in a single newline.
+    #[structopt(long)]
+    no_normalize: bool,
+
+    /// Give up on a part when a single run takes longer than this many seconds.
+    #[structopt(short, long, parse(try_from_str = parse_seconds))]
+    timeout: Option<Duration>,
+
+    /// Output format: text, json, csv or markdown
+    #[structopt(short, long, default_value("text"))]
+    format: Format,
+
+    /// Count the memory allocated by each part: the peak, the total and the number of
+    /// allocations. Only works with a single job.
+    #[structopt(long)]
+    mem: bool,
+
+    /// Override a puzzle parameter, e.g. `--param row=10`. Can be given several times.
+    #[structopt(long = "param", value_name("name=value"), number_of_values(1))]
+    params: Vec<ParamOverride>,
+}
+
+#[derive(Debug, StructOpt)]
+enum Command {
+    /// Download the input of a day into the input directory, unless it is already there. The
+    /// session token and the server are configured in `~/.config/aoc/config`.
+    Fetch {
+        /// Required when there are several years.
+        #[structopt(short, long)]
+        year: Option<u32>,
+
+        #[structopt(short, long)]
+        day: u32,
+    },
+    /// Solve a part with the real input and submit the answer, unless it is known to be wrong.
+    /// Every attempt is logged next to the input, and correct answers are saved to its answers file.
+    Submit {
+        /// Required when there are several years.
+        #[structopt(short, long)]
+        year: Option<u32>,
+
+        #[structopt(short, long)]
+        day: u32,
+
+        #[structopt(short, long, default_value("1"))]
+        part: Part,
+    },
+    /// Add the next day of a year: a module with placeholder solutions, its entry in the `days!`
+    /// list of the year and a directory for its inputs.
+    New {
+        /// Required when there are several years.
+        #[structopt(short, long)]
+        year: Option<u32>,
+
+        #[structopt(short, long)]
+        day: u32,
+
+        /// The title of the puzzle.
+        #[structopt(short, long)]
+        title: Option<String>,
+    },
+    /// Time the solutions with the real inputs, one part after the other, and save the timings as
+    /// a baseline or compare them against one. Baselines are stored in `baselines/<name>.json`.
+    Bench {
+        /// Only benchmark this year.
+        #[structopt(short, long)]
+        year: Option<u32>,
+
+        /// Only benchmark this day.
+        #[structopt(short, long)]
+        day: Option<u32>,
+
+        /// Number of timed runs of each part, after one run to warm up.
+        #[structopt(short, long, default_value("20"))]
+        runs: usize,
+
+        /// Save the timings as the baseline with this name.
+        #[structopt(long, value_name("name"))]
+        save: Option<String>,
+
+        /// Compare the timings against the baseline with this name and fail on regressions.
+        #[structopt(long, value_name("name"))]
+        compare: Option<String>,
+
+        /// How many percent slower the median of a part may get before a significant change counts
+        /// as a regression.
+        #[structopt(long, default_value("10"))]
+        threshold: f64,
+    },
+}
+
+fn parse_seconds(s: &str) -> anyhow::Result<Duration> {
+    let seconds: f64 = s.parse()?;
+    anyhow::ensure!(
+        seconds.is_finite() && seconds > 0.0,
+        "must be a positive number of seconds"
+    );
+    Ok(Duration::from_secs_f64(seconds))
+}
+
+#[derive(Clone, Copy)]
+enum Solver {
+    Plain(fn(&[u8]) -> anyhow::Result<Answer>),
+    WithParams(fn(&[u8], &Params) -> anyhow::Result<Answer>),
+    Unsolved,
+}
+
+#[derive(Clone, Copy)]
+pub struct Day {
+    part1: Solver,
+    part2: Solver,
+    params: &'static [Param],
+    title: &'static str,
+    bench: bool,
+}
+
+impl Day {
+    pub const fn new(
+        part1: fn(&[u8]) -> anyhow::Result<Answer>,
+        part2: fn(&[u8]) -> anyhow::Result<Answer>,
+    ) -> Self {
+        Self {
+            part1: Solver::Plain(part1),
+            part2: Solver::Plain(part2),
+            params: &[],
+            title: "",
+            bench: true,
+        }
+    }
+
+    /// A day whose solutions depend on parameters that differ between the example and the real
+    /// input. The defaults of the parameters are for the real input.
+    pub const fn with_params(
+        params: &'static [Param],
+        part1: fn(&[u8], &Params) -> anyhow::Result<Answer>,
+        part2: fn(&[u8], &Params) -> anyhow::Result<Answer>,
+    ) -> Self {
+        Self {
+            part1: Solver::WithParams(part1),
+            part2: Solver::WithParams(part2),
+            params,
+            title: "",
+            bench: true,
+        }
+    }
+
+    /// Set the title of the puzzle.
+    pub const fn titled(mut self, title: &'static str) -> Self {
+        self.title = title;
+        self
+    }
+
+    /// Leave the day out of the benchmarks, e.g. because it takes too long.
+    pub const fn without_bench(mut self) -> Self {
+        self.bench = false;
+        self
+    }
+
+    pub fn params(&self) -> &'static [Param] {
+        self.params
+    }
+
+    pub fn title(&self) -> &'static str {
+        self.title
+    }
+
+    pub fn bench(&self) -> bool {
+        self.bench
+    }
+
+    /// Solve a part with the default parameters.
+    pub fn solve(&self, part: Part, input: &[u8]) -> anyhow::Result<Answer> {
+        self.solve_with(part, input, &Params::defaults(self.params))
+    }
+
+    pub fn solve_with(&self, part: Part, input: &[u8], params: &Params) -> anyhow::Result<Answer> {
+        let solver = match part {
+            Part::One => self.part1,
+            Part::Two => self.part2,
+        };
+        match solver {
+            Solver::Plain(solver) => solver(input),
+            Solver::WithParams(solver) => solver(input, params),
+            Solver::Unsolved => anyhow::bail!("no solution for this day"),
+        }
+    }
+
+    /// A placeholder for a day that has not been solved yet. Such days are reported as `UNSOLVED`
+    /// and do not need an input.
+    pub const fn unsolved() -> Self {
+        Self {
+            part1: Solver::Unsolved,
+            part2: Solver::Unsolved,
+            params: &[],
+            title: "",
+            bench: false,
+        }
+    }
+
+    pub fn is_unsolved(&self) -> bool {
+        matches!(
+            (self.part1, self.part2),
+            (Solver::Unsolved, Solver::Unsolved)
+        )
+    }
+}
+
+/// The solutions of one Advent of Code event.
+#[derive(Clone, Copy)]
+pub struct Year {
+    pub year: u32,
+    /// The solutions, starting with day 1, usually the `DAYS` registered with [`crate::days!`].
+    pub days: &'static [Day],
+    /// Default directory containing the `dayN/input.txt` files.
+    pub inputs: &'static str,
+    /// Directory containing the `lib.rs` and `dayN.rs` files, where `aoc new` adds days.
+    pub src: &'static str,
+}
+
+impl Year {
+    /// The solution of a day, counting from 1.
+    pub fn day(&self, number: u32) -> Option<&Day> {
+        number
+            .checked_sub(1)
+            .and_then(|index| self.days.get(index as usize))
+    }
+
+    /// The days with their numbers.
+    pub fn numbered_days(&self) -> impl Iterator<Item = (u32, &Day)> {
+        (1..).zip(self.days)
+    }
+
+    /// The directory with the inputs of this year. If `AOC_INPUT_DIR` is set, that is its
+    /// subdirectory named after the year when it is `shared` by several years or has such a
+    /// subdirectory, and `AOC_INPUT_DIR` itself otherwise.
+    pub fn input_dir(&self, shared: bool) -> PathBuf {
+        match std::env::var_os("AOC_INPUT_DIR").map(PathBuf::from) {
+            Some(dir) => {
+                let year_dir = dir.join(self.year.to_string());
+                if shared || year_dir.is_dir() {
+                    year_dir
+                } else {
+                    dir
+                }
+            }
+            None => PathBuf::from(self.inputs),
+        }
+    }
+
+    /// Read and normalize the real input of a day, from the same directory as the runner.
+    pub fn read_input(&self, number: u32) -> anyhow::Result<Vec<u8>> {
+        let path = self
+            .input_dir(false)
+            .join(format!("day{}/input.txt", number));
+        let contents = read_bytes(&path).with_context(|| format!("reading {}", path.display()))?;
+        Ok(normalize::normalize(&contents).0)
+    }
+}
+
+pub fn aoc_main(years: &[Year]) -> anyhow::Result<()> {
+    run(AocOpt::from_args(), years)
+}
+
+pub fn run(opt: AocOpt, years: &[Year]) -> anyhow::Result<()> {
+    let input_type = if opt.example { "example" } else { "input" };
+
+    let several_years = years.len() > 1;
+    let input_dir = |year: &Year| year.input_dir(several_years);
+
+    let years = select_years(years, opt.year)?;
+    if opt.mem {
+        anyhow::ensure!(opt.jobs == 1, "--mem only works with a single job");
+        // A solver abandoned after a timeout keeps running and allocating on its own thread.
+        anyhow::ensure!(opt.timeout.is_none(), "--mem does not work with --timeout");
+        memory::enable()?;
+    }
+
+    if let Some(command) = opt.command {
+        let (year, day) = match &command {
+            Command::Fetch { year, day }
+            | Command::Submit { year, day, .. }
+            | Command::New { year, day, .. } => (*year, check_day(*day)?),
+            Command::Bench {
+                year,
+                day,
+                runs,
+                save,
+                compare,
+                threshold,
+            } => {
+                let years = select_years(years, *year)?;
+                if let Some(day) = *day {
+                    check_day(day)?;
+                    anyhow::ensure!(
+                        years.iter().any(|year| year.day(day).is_some()),
+                        "no solution for day {}",
+                        day
+                    );
+                }
+                let options = BenchOptions {
+                    day: *day,
+                    runs: *runs,
+                    save: save.as_deref(),
+                    compare: compare.as_deref(),
+                    threshold: *threshold / 100.0,
+                };
+                return bench(years, &input_dir, &options, &opt.params);
+            }
+        };
+        let year = match (select_years(years, year)?, year) {
+            ([year], _) => year,
+            (_, None) => anyhow::bail!("--year is required when there are several years"),
+            (_, Some(_)) => unreachable!("selecting a year yields a single year"),
+        };
+        if let Command::New { title, .. } = &command {
+            let module = scaffold::new_day(year, day, title.as_deref(), &input_dir(year))?;
+            eprintln!(
+                "Created {}, run `cargo build` to include it",
+                module.display()
+            );
+            return Ok(());
+        }
+        if let Command::Submit { .. } = &command {
+            anyhow::ensure!(year.day(day).is_some(), "no solution for day {}", day);
+        }
+        let path = input_dir(year).join(format!("day{}/input.txt", day));
+        let config = Config::load()?;
+        return match command {
+            Command::Fetch { .. } => {
+                match website::fetch_input(&config, year.year, day, &path)? {
+                    Fetched::Downloaded => eprintln!("Downloaded {}", path.display()),
+                    Fetched::Cached => eprintln!(
+                        "{} already exists, not downloading it again",
+                        path.display()
+                    ),
+                }
+                Ok(())
+            }
+            Command::Submit { part, .. } => submit(&config, year, day, part, &path, &opt.params),
+            Command::New { .. } | Command::Bench { .. } => unreachable!("handled above"),
+        };
+    }
+
+    if opt.all {
+        let mut inputs = Vec::new();
+        let mut missing = Vec::new();
+        for year in years {
+            let base_dir = match &opt.input {
+                Some(dir) if years.len() > 1 => dir.join(year.year.to_string()),
+                Some(dir) => dir.clone(),
+                None => input_dir(year),
+            };
+
+            for (day_number, day) in year.numbered_days() {
+                if day.is_unsolved() {
+                    let (contents, answers) = (Vec::new(), Answers::default());
+                    let params = Params::defaults(day.params());
+                    inputs.push((year.year, day_number, day, contents, answers, params));
+                    continue;
+                }
+                let inpath = base_dir.join(format!("day{}/{}.txt", day_number, input_type));
+                if let Some(hint) = missing_input(&inpath, year.year, day_number, opt.example) {
+                    missing.push((year.year, day_number, hint));
+                    continue;
+                }
+                let contents = load_input(&inpath, !opt.no_normalize)?;
+                let answers = Answers::load(&inpath)?;
+                let params = resolve_params(day, &answers, &opt.params)
+                    .with_context(|| format!("parameters for {}", inpath.display()))?;
+                inputs.push((year.year, day_number, day, contents, answers, params));
+            }
+        }
+
+        let tasks = inputs
+            .iter()
+            .flat_map(|(year, day_number, day, contents, answers, params)| {
+                [Part::One, Part::Two].map(|part| Task {
+                    year: *year,
+                    day_number: *day_number,
+                    day,
+                    part,
+                    contents,
+                    answers,
+                    params,
+                })
+            })
+            .collect::<Vec<_>>();
+        for param in &opt.params {
+            let declared = years
+                .iter()
+                .flat_map(|year| year.numbered_days())
+                .any(|(_, day)| day.params().iter().any(|p| p.name == param.name));
+            anyhow::ensure!(declared, "no day has a parameter named {}", param.name);
+        }
+        let jobs = match opt.jobs {
+            0 => std::thread::available_parallelism().map_or(1, |n| n.get()),
+            jobs => jobs,
+        };
+
+        let before = Instant::now();
+        let mut results = run_tasks(&tasks, jobs, opt.repeat, opt.timeout);
+        let duration = before.elapsed();
+        results.extend(missing.into_iter().flat_map(|(year, day, hint)| {
+            [Part::One, Part::Two].map(|part| PartResult {
+                year,
+                day,
+                part,
+                outcome: Outcome::Missing(hint.clone()),
+                timings: Timings::default(),
+                memory: None,
+            })
+        }));
+        results.sort_by_key(|result| (result.year, result.day, result.part.number()));
+
+        report::write_results(&mut std::io::stdout().lock(), opt.format, &results)?;
+
+        eprintln!("year\tpart 1 ms\tpart 2 ms\ttotal ms");
+        for year in years {
+            let total = |part| -> Duration {
+                results
+                    .iter()
+                    .filter(|r| r.year == year.year && r.part == part)
+                    .map(|r| r.timings.total())
+                    .sum()
+            };
+            let (time1, time2) = (total(Part::One), total(Part::Two));
+            eprintln!(
+                "{}\t{:.3}\t{:.3}\t{:.3}",
+                year.year,
+                millis(time1),
+                millis(time2),
+                millis(time1 + time2)
+            );
+        }
+        let sum: Duration = results.iter().map(|r| r.timings.total()).sum();
+        eprintln!(
+            "Took {:.3} ms wall-clock, {:.3} ms sum of individual times ({} {})",
+            millis(duration),
+            millis(sum),
+            jobs,
+            if jobs == 1 { "job" } else { "jobs" }
+        );
+
+        check_results(&results)
+    } else {
+        let year = match years {
+            [year] => year,
+            _ => anyhow::bail!("--year is required for running a single day"),
+        };
+        let day_number = check_day(opt.day.unwrap())?;
+        let day = year.day(day_number).context("no such day")?;
+        anyhow::ensure!(
+            !day.is_unsolved(),
+            "day {} has not been solved yet",
+            day_number
+        );
+        if !day.title().is_empty() {
+            eprintln!("{} day {}: {}", year.year, day_number, day.title());
+        }
+        let infile = opt.input.unwrap_or_else(|| {
+            input_dir(year).join(format!("day{}/{}.txt", day_number, input_type))
+        });
+        check_input_exists(&infile, year.year, day_number, opt.example)?;
+        let contents = load_input(&infile, !opt.no_normalize)?;
+        let answers = Answers::load(&infile)?;
+        for param in &opt.params {
+            anyhow::ensure!(
+                day.params().iter().any(|p| p.name == param.name),
+                "day {} has no parameter named {}",
+                day_number,
+                param.name
+            );
+        }
+        let params = resolve_params(day, &answers, &opt.params)?;
+
+        let task = Task {
+            year: year.year,
+            day_number,
+            day,
+            part: opt.part,
+            contents: &contents,
+            answers: &answers,
+            params: &params,
+        };
+        let result = task.solve(opt.repeat, opt.timeout);
+        match (opt.format, &result.outcome) {
+            (Format::Text, Outcome::Solved { answer, .. }) => println!("{}", answer),
+            (Format::Text, _) => {}
+            (format, _) => report::write_results(
+                &mut std::io::stdout().lock(),
+                format,
+                std::slice::from_ref(&result),
+            )?,
+        }
+        if result.timings.runs() > 1 {
+            eprintln!("{}", result.timings);
+        } else if result.timings.runs() == 1 {
+            eprintln!("Took {:.3} ms", millis(result.timings.total()));
+        }
+        if let Some(memory) = &result.memory {
+            eprintln!("Memory: {}", memory);
+        }
+
+        match &result.outcome {
+            Outcome::Solved {
+                verdict: Verdict::Fail,
+                ..
+            } => anyhow::bail!(
+                "answer does not match the expected {:?}",
+                answers.get(opt.part).unwrap_or_default()
+            ),
+            Outcome::Solved { verdict, .. } => eprintln!("{}", verdict),
+            outcome => anyhow::bail!("{}", outcome.error().unwrap_or_default()),
+        }
+        Ok(())
+    }
+}
+
+/// One part of a day together with its input.
+struct Task<'a> {
+    year: u32,
+    day_number: u32,
+    day: &'a Day,
+    part: Part,
+    contents: &'a [u8],
+    answers: &'a Answers,
+    params: &'a Params,
+}
+
+impl Task<'_> {
+    /// Run the part `repeat` times, stopping at the first failure. With a timeout, the runs happen
+    /// on a separate thread, which is abandoned when a run takes too long.
+    fn solve(&self, repeat: usize, timeout: Option<Duration>) -> PartResult {
+        if self.day.is_unsolved() {
+            return PartResult {
+                year: self.year,
+                day: self.day_number,
+                part: self.part,
+                outcome: Outcome::Unsolved,
+                timings: Timings::default(),
+                memory: None,
+            };
+        }
+        let repeat = repeat.max(1);
+        let (timings, memory, output) = match timeout {
+            None => collect_runs(repeat, || {
+                Ok(run_once(|| {
+                    self.day.solve_with(self.part, self.contents, self.params)
+                }))
+            }),
+            Some(timeout) => {
+                let (day, part) = (*self.day, self.part);
+                let input = self.contents.to_vec();
+                let params = self.params.clone();
+                let (sender, receiver) = mpsc::channel();
+                std::thread::spawn(move || {
+                    for _ in 0..repeat {
+                        let run = run_once(|| day.solve_with(part, &input, &params));
+                        let failed = run.2.is_err();
+                        if sender.send(run).is_err() || failed {
+                            break;
+                        }
+                    }
+                });
+                collect_runs(repeat, || {
+                    receiver.recv_timeout(timeout).map_err(|err| match err {
+                        mpsc::RecvTimeoutError::Timeout => Outcome::TimedOut(timeout),
+                        mpsc::RecvTimeoutError::Disconnected => {
+                            Outcome::Failed("solver thread exited unexpectedly".to_owned())
+                        }
+                    })
+                })
+            }
+        };
+
+        let outcome = match output {
+            Ok(answer) => Outcome::Solved {
+                verdict: self.answers.check(self.part, &answer),
+                answer,
+            },
+            Err(outcome) => outcome,
+        };
+        PartResult {
+            year: self.year,
+            day: self.day_number,
+            part: self.part,
+            outcome,
+            timings,
+            memory,
+        }
+    }
+}
+
+type Run = (Duration, Option<MemoryUsage>, Result<Answer, Outcome>);
+
+thread_local! {
+    /// Whether the thread is running a solver, whose panics end up in its [`Outcome`].
+    static SOLVING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Time a single run of a solver, turning errors and panics into the corresponding [`Outcome`].
+fn run_once(solver: impl FnOnce() -> anyhow::Result<Answer> + panic::UnwindSafe) -> Run {
+    let before = Instant::now();
+    let (output, memory) = memory::measure(|| {
+        SOLVING.with(|solving| solving.set(true));
+        let output = panic::catch_unwind(solver);
+        SOLVING.with(|solving| solving.set(false));
+        output
+    });
+    let elapsed = before.elapsed();
+    let output = match output {
+        Ok(Ok(answer)) => Ok(answer),
+        Ok(Err(err)) => Err(Outcome::Failed(format!("{:#}", err))),
+        Err(payload) => Err(Outcome::Panicked(panic_message(payload))),
+    };
+    (elapsed, memory, output)
+}
+
+/// Collect the timings of the runs until one fails. The memory usage is that of the last run.
+fn collect_runs(
+    repeat: usize,
+    mut next_run: impl FnMut() -> Result<Run, Outcome>,
+) -> (Timings, Option<MemoryUsage>, Result<Answer, Outcome>) {
+    let mut timings = Timings::default();
+    let mut memory = None;
+    let mut output = Ok(Answer::NotApplicable);
+    for _ in 0..repeat {
+        let (elapsed, usage, result) = match next_run() {
+            Ok(run) => run,
+            Err(outcome) => return (timings, memory, Err(outcome)),
+        };
+        timings.record(elapsed);
+        memory = usage;
+        output = result;
+        if output.is_err() {
+            break;
+        }
+    }
+    (timings, memory, output)
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic payload".to_owned()
+    }
+}
+
+/// Run `f` with a panic hook that stays silent for the panics of solvers, which are reported as
+/// `PANIC` instead of interrupting the output. Other panics go to the previous hook.
+fn with_quiet_panics<T>(f: impl FnOnce() -> T) -> T {
+    let previous = Arc::new(panic::take_hook());
+    let fallback = Arc::clone(&previous);
+    panic::set_hook(Box::new(move |info| {
+        if !SOLVING.with(Cell::get) {
+            fallback(info)
+        }
+    }));
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(f));
+    // Other threads may have replaced the hook in the meantime, so this does not try to get the
+    // previous one back out of the Arc.
+    drop(panic::take_hook());
+    panic::set_hook(Box::new(move |info| previous(info)));
+    result.unwrap_or_else(|payload| panic::resume_unwind(payload))
+}
+
+/// Solve the tasks on `jobs` threads. The results are in the same order as the tasks.
+fn run_tasks(
+    tasks: &[Task],
+    jobs: usize,
+    repeat: usize,
+    timeout: Option<Duration>,
+) -> Vec<PartResult> {
+    with_quiet_panics(|| solve_tasks(tasks, jobs, repeat, timeout))
+}
+
+fn solve_tasks(
+    tasks: &[Task],
+    jobs: usize,
+    repeat: usize,
+    timeout: Option<Duration>,
+) -> Vec<PartResult> {
+    if jobs <= 1 {
+        return tasks
+            .iter()
+            .map(|task| task.solve(repeat, timeout))
+            .collect();
+    }
+
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+    std::thread::scope(|scope| {
+        for _ in 0..jobs.min(tasks.len()) {
+            let sender = sender.clone();
+            let next = &next;
+            scope.spawn(move || loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(task) = tasks.get(index) else {
+                    break;
+                };
+                sender.send((index, task.solve(repeat, timeout))).unwrap();
+            });
+        }
+    });
+    drop(sender);
+
+    let mut results = receiver.into_iter().collect::<Vec<_>>();
+    results.sort_by_key(|(index, _)| *index);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+/// Summarize the verdicts on stderr, failing if any answer was wrong or any part did not finish.
+fn check_results(results: &[PartResult]) -> anyhow::Result<()> {
+    let count = |status| {
+        results
+            .iter()
+            .filter(|r| r.outcome.status() == status)
+            .count()
+    };
+    let failed = count("FAIL");
+    let unfinished = count("ERROR") + count("PANIC") + count("TIMEOUT");
+    eprintln!(
+        "{} passed, {} failed, {} unknown, {} unsolved, {} missing, {} errors, {} panicked, {} timed out",
+        count("PASS"),
+        failed,
+        count("UNKNOWN"),
+        count("UNSOLVED"),
+        count("MISSING"),
+        count("ERROR"),
+        count("PANIC"),
+        count("TIMEOUT"),
+    );
+    for result in results {
+        if let Some(message) = result.outcome.error() {
+            eprintln!(
+                "{} day{}.{}: {}",
+                result.year,
+                result.day,
+                result.part.number(),
+                message
+            );
+        }
+    }
+    if failed + unfinished > 0 {
+        anyhow::bail!(
+            "{} answers did not match, {} parts did not finish",
+            failed,
+            unfinished
+        );
+    }
+    Ok(())
+}
+
+/// The parameters of a day for an input file: the defaults, overridden by the answers file of the
+/// input and then by the command line. Command line parameters the day does not have are ignored.
+pub(crate) fn resolve_params(
+    day: &Day,
+    answers: &Answers,
+    overrides: &[ParamOverride],
+) -> anyhow::Result<Params> {
+    let mut params = Params::defaults(day.params());
+    for (name, value) in answers.params() {
+        let value = value
+            .parse()
+            .with_context(|| format!("parameter {} must be an integer", name))?;
+        anyhow::ensure!(params.set(name, value), "no parameter named {}", name);
+    }
+    for param in overrides {
+        params.set(&param.name, param.value);
+    }
+    Ok(params)
+}
+
+/// Solve a part and submit the answer if it is neither known to be right nor known to be wrong.
+fn submit(
+    config: &Config,
+    year: &Year,
+    day_number: u32,
+    part: Part,
+    inpath: &Path,
+    overrides: &[ParamOverride],
+) -> anyhow::Result<()> {
+    check_input_exists(inpath, year.year, day_number, false)?;
+    let day = year.day(day_number).context("no such day")?;
+    let contents = load_input(inpath, true)?;
+    let mut answers = Answers::load(inpath)?;
+    let params = resolve_params(day, &answers, overrides)?;
+    let answer = day.solve_with(part, &contents, &params)?;
+    println!("{}", answer);
+
+    match answers.expected(part) {
+        Some(expected) if expected == answer => {
+            eprintln!(
+                "Already verified in {}",
+                Answers::path_for(inpath).display()
+            );
+            return Ok(());
+        }
+        Some(expected) => anyhow::bail!(
+            "{} already has a different answer: {}",
+            Answers::path_for(inpath).display(),
+            expected
+        ),
+        None => {}
+    }
+    anyhow::ensure!(
+        !matches!(answer, Answer::Image(_) | Answer::NotApplicable),
+        "this answer cannot be submitted"
+    );
+
+    let mut log = SubmissionLog::load(inpath)?;
+    log.check(part, &answer, submissions::unix_time())?;
+    let response =
+        website::submit_answer(config, year.year, day_number, part, &answer.to_string())?;
+    log.append(inpath, Submission::now(part, response, &answer))?;
+    match response {
+        Response::Correct => {
+            answers.set(part, &answer);
+            answers.save(inpath)?;
+            eprintln!(
+                "That's the right answer, saved to {}",
+                Answers::path_for(inpath).display()
+            );
+            Ok(())
+        }
+        response => anyhow::bail!("{} is {}", answer, submissions::describe(response)),
+    }
+}
+
+struct BenchOptions<'a> {
+    day: Option<u32>,
+    runs: usize,
+    save: Option<&'a str>,
+    compare: Option<&'a str>,
+    /// The relative slowdown that counts as a regression, e.g. `0.1` for 10%.
+    threshold: f64,
+}
+
+/// Time the parts of all benchmarked days one after the other, then compare the timings against
+/// a baseline and save them, as requested.
+fn bench(
+    years: &[Year],
+    input_dir: &dyn Fn(&Year) -> PathBuf,
+    options: &BenchOptions,
+    overrides: &[ParamOverride],
+) -> anyhow::Result<()> {
+    let baseline = match options.compare {
+        Some(name) => Some(Baseline::load(&Baseline::path_for(name))?),
+        None => None,
+    };
+
+    let mut inputs = Vec::new();
+    for year in years {
+        for (day_number, day) in year.numbered_days() {
+            if !day.bench() || options.day.map_or(false, |only| only != day_number) {
+                continue;
+            }
+            let inpath = input_dir(year).join(format!("day{}/input.txt", day_number));
+            check_input_exists(&inpath, year.year, day_number, false)?;
+            let contents = load_input(&inpath, true)?;
+            let answers = Answers::load(&inpath)?;
+            let params = resolve_params(day, &answers, overrides)?;
+            inputs.push((year.year, day_number, day, contents, answers, params));
+        }
+    }
+    anyhow::ensure!(!inputs.is_empty(), "no days to benchmark");
+    let tasks = inputs
+        .iter()
+        .flat_map(|(year, day_number, day, contents, answers, params)| {
+            [Part::One, Part::Two].map(|part| Task {
+                year: *year,
+                day_number: *day_number,
+                day,
+                part,
+                contents,
+                answers,
+                params,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let mut results = run_tasks(&tasks, 1, options.runs.max(1) + 1, None);
+    for result in &mut results {
+        let samples = result.timings.samples().iter().skip(1).copied().collect();
+        result.timings = Timings::from_samples(samples);
+    }
+    check_results(&results)?;
+
+    let regressions = match &baseline {
+        Some(baseline) => {
+            let changes = baseline::compare(baseline, &results);
+            baseline::write_comparison(&mut std::io::stdout().lock(), &changes, options.threshold)?;
+            changes
+                .iter()
+                .filter(|change| change.is_regression(options.threshold))
+                .count()
+        }
+        None => {
+            report::write_results(&mut std::io::stdout().lock(), Format::Text, &results)?;
+            0
+        }
+    };
+    if let Some(name) = options.save {
+        let path = Baseline::path_for(name);
+        Baseline::from_results(&results, submissions::unix_time()).save(&path)?;
+        eprintln!("Saved the timings to {}", path.display());
+    }
+    anyhow::ensure!(
+        regressions == 0,
+        "{} parts got more than {}% slower",
+        regressions,
+        options.threshold * 100.0
+    );
+    Ok(())
+}
+
+fn select_years(years: &[Year], year: Option<u32>) -> anyhow::Result<&[Year]> {
+    match year {
+        Some(year) => {
+            let selected = years
+                .iter()
+                .find(|y| y.year == year)
+                .with_context(|| format!("no solutions for {}", year))?;
+            Ok(std::slice::from_ref(selected))
+        }
+        None => Ok(years),
+    }
+}
+
+/// Fail unless the day is one of the 25 days of a year.
+fn check_day(day: u32) -> anyhow::Result<u32> {
+    anyhow::ensure!(
+        (1..=25).contains(&day),
+        "day {} is not between 1 and 25",
+        day
+    );
+    Ok(day)
+}
+
+/// Fail with a hint on how to get the input if it does not exist.
+fn check_input_exists(inpath: &Path, year: u32, day: u32, example: bool) -> anyhow::Result<()> {
+    match missing_input(inpath, year, day, example) {
+        Some(hint) => Err(anyhow::anyhow!(hint)),
+        None => Ok(()),
+    }
+}
+
+/// A hint on how to get the input if it does not exist.
+fn missing_input(inpath: &Path, year: u32, day: u32, example: bool) -> Option<String> {
+    if inpath.exists() {
+        None
+    } else if example {
+        Some(format!(
+            "{} does not exist, copy the example from the puzzle description there",
+            inpath.display()
+        ))
+    } else {
+        Some(format!(
+            "{} does not exist, download it with `aoc fetch --year {} --day {}`",
+            inpath.display(),
+            year,
+            day
+        ))
+    }
+}
+
+/// Read an input file, normalizing it if requested and reporting the changes on stderr.
+fn load_input(inpath: &Path, normalize: bool) -> anyhow::Result<Vec<u8>> {
+    let contents = read_bytes(inpath).with_context(|| format!("reading {}", inpath.display()))?;
+    if !normalize {
+        return Ok(contents);
+    }
+    let (contents, changes) = normalize::normalize(&contents);
+    if !changes.is_empty() {
+        eprintln!("{}: {}", inpath.display(), changes);
+    }
+    Ok(contents)
+}
+
+fn read_bytes(inpath: &Path) -> Result<Vec<u8>, std::io::Error> {
+    let mut file = std::fs::File::open(inpath)?;
+    let mut contents = Vec::new();
+    file.read_to_end(&mut contents)?;
+    Ok(contents)
+}
+
+#[test]
+fn test_run_tasks_in_order() {
+    fn length(input: &[u8]) -> anyhow::Result<Answer> {
+        Ok(input.len().into())
+    }
+    fn no_solution(_: &[u8]) -> anyhow::Result<Answer> {
+        anyhow::bail!("no solution for this day");
+    }
+    let day = Day::new(length, no_solution);
+    let inputs = (0..20).map(|n| vec![0; n]).collect::<Vec<_>>();
+    let answers = Answers::default();
+    let params = Params::default();
+    let tasks = inputs
+        .iter()
+        .enumerate()
+        .flat_map(|(index, contents)| {
+            [Part::One, Part::Two].map(|part| Task {
+                year: 2022,
+                day_number: index as u32 + 1,
+                day: &day,
+                part,
+                contents,
+                answers: &answers,
+                params: &params,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let sequential = run_tasks(&tasks, 1, 1, None);
+    let parallel = run_tasks(&tasks, 4, 1, None);
+    assert_eq!(parallel.len(), tasks.len());
+    for (index, (a, b)) in sequential.iter().zip(&parallel).enumerate() {
+        assert_eq!((a.day, a.part), (b.day, b.part));
+        assert_eq!(a.outcome, b.outcome);
+        if a.part == Part::One {
+            assert_eq!(a.outcome.answer(), Some(&Answer::from(index / 2)));
+        } else {
+            assert_eq!(a.outcome.status(), "ERROR");
+        }
+    }
+}
+
+#[test]
+fn test_solve_isolates_failures() {
+    fn panics(_: &[u8]) -> anyhow::Result<Answer> {
+        panic!("index out of bounds")
+    }
+    fn hangs(_: &[u8]) -> anyhow::Result<Answer> {
+        std::thread::sleep(Duration::from_secs(10));
+        Ok("too late".into())
+    }
+    let day = Day::new(panics, hangs);
+    let answers = Answers::default();
+    let params = Params::default();
+    let task = |part| Task {
+        year: 2022,
+        day_number: 1,
+        day: &day,
+        part,
+        contents: b"",
+        answers: &answers,
+        params: &params,
+    };
+
+    let result = task(Part::One).solve(3, None);
+    assert_eq!(
+        result.outcome,
+        Outcome::Panicked("index out of bounds".to_owned())
+    );
+    assert_eq!(result.timings.runs(), 1);
+    assert!(!SOLVING.with(Cell::get));
+    let timeout = Duration::from_millis(10);
+    let result = task(Part::One).solve(3, Some(timeout));
+    assert_eq!(result.outcome.status(), "PANIC");
+    let result = task(Part::Two).solve(3, Some(timeout));
+    assert_eq!(result.outcome, Outcome::TimedOut(timeout));
+    assert_eq!(result.timings.runs(), 0);
+}
@@ -0,0 +1,141 @@
+//! Adding the next day to a year, as done by `aoc new --year 2022 --day 12`.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+
+use crate::runner::Year;
+
+const DAYS_MACRO: &str = "aoc_core::days! {";
+
+/// Create the module of a day from a template, add it to the `days!` list in the `lib.rs` of the
+/// year and create the directory for its inputs. Returns the path of the new module.
+pub fn new_day(
+    year: &Year,
+    day: u32,
+    title: Option<&str>,
+    input_dir: &Path,
+) -> anyhow::Result<PathBuf> {
+    let src = Path::new(year.src);
+    let module = src.join(format!("day{}.rs", day));
+    anyhow::ensure!(!module.exists(), "{} already exists", module.display());
+    let lib = src.join("lib.rs");
+    let contents =
+        std::fs::read_to_string(&lib).with_context(|| format!("reading {}", lib.display()))?;
+    let contents = register(&contents, day)
+        .with_context(|| format!("adding day {} to {}", day, lib.display()))?;
+
+    std::fs::write(&module, template(title))
+        .with_context(|| format!("writing {}", module.display()))?;
+    std::fs::write(&lib, contents).with_context(|| format!("writing {}", lib.display()))?;
+    let dir = input_dir.join(format!("day{}", day));
+    std::fs::create_dir_all(&dir).with_context(|| format!("creating {}", dir.display()))?;
+    Ok(module)
+}
+
+/// Append `dayN` to the `days!` list of a `lib.rs`. Days are numbered by their position in the
+/// list, so it has to contain exactly the days before.
+fn register(lib: &str, day: u32) -> anyhow::Result<String> {
+    let start = lib.find(DAYS_MACRO).context("no `aoc_core::days!` list")?;
+    let body_start = start + DAYS_MACRO.len();
+    let end = body_start
+        + lib[body_start..]
+            .find('}')
+            .context("unterminated `aoc_core::days!` list")?;
+    let entries = lib[body_start..end]
+        .split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .collect::<Vec<_>>();
+    for (number, entry) in (1..).zip(&entries) {
+        anyhow::ensure!(
+            *entry == format!("day{}", number),
+            "expected day{} at position {} of the list, found {}",
+            number,
+            number,
+            entry
+        );
+    }
+    let next = entries.len() as u32 + 1;
+    anyhow::ensure!(day >= next, "day {} is already registered", day);
+    anyhow::ensure!(day == next, "day {} has to be added first", next);
+
+    let insert = lib[..end].trim_end().len();
+    let separator = if entries.is_empty() || lib[..insert].ends_with(',') {
+        ""
+    } else {
+        ","
+    };
+    Ok(format!(
+        "{}{}\n    day{},{}",
+        &lib[..insert],
+        separator,
+        day,
+        &lib[insert..]
+    ))
+}
+
+/// The module of a day that is not solved yet, with a parser for the lines of the input to start
+/// from.
+fn template(title: Option<&str>) -> String {
+    let titled = match title {
+        Some(title) => format!(".titled({:?})", title),
+        None => String::new(),
+    };
+    format!(
+        r#"use nom::{{bytes::complete::is_not, multi::many1, sequence::terminated}};
+
+use crate::{{
+    parsers::{{self, newline}},
+    Answer, Day,
+}};
+
+// Replace with `Day::new(part1, part2)` once part 1 is solved.
+pub static RUN: Day = Day::unsolved(){};
+
+pub fn part1(input: &[u8]) -> anyhow::Result<Answer> {{
+    let _lines = parse_input(input)?;
+    anyhow::bail!("not solved yet")
+}}
+
+pub fn part2(input: &[u8]) -> anyhow::Result<Answer> {{
+    let _lines = parse_input(input)?;
+    anyhow::bail!("not solved yet")
+}}
+
+fn parse_input(input: &[u8]) -> anyhow::Result<Vec<&[u8]>> {{
+    parsers::parse(many1(terminated(is_not("\n"), newline)), input)
+}}
+"#,
+        titled
+    )
+}
+
+#[test]
+fn test_register() {
+    let lib =
+        "use std::str::FromStr;\n\naoc_core::days! {\n    day1,\n    day2,\n}\n\npub use x;\n";
+    assert_eq!(
+        register(lib, 3).unwrap(),
+        "use std::str::FromStr;\n\naoc_core::days! {\n    day1,\n    day2,\n    day3,\n}\n\npub use x;\n"
+    );
+    assert_eq!(
+        register("aoc_core::days! {\n    day1\n}\n", 2).unwrap(),
+        "aoc_core::days! {\n    day1,\n    day2,\n}\n"
+    );
+    assert_eq!(
+        register("aoc_core::days! {\n}\n", 1).unwrap(),
+        "aoc_core::days! {\n    day1,\n}\n"
+    );
+    assert!(register(lib, 2).is_err());
+    assert!(register(lib, 4).is_err());
+    assert!(register("aoc_core::days! { day1, day3 }", 4).is_err());
+    assert!(register("pub mod day1;", 2).is_err());
+}
+
+#[test]
+fn test_template() {
+    assert!(template(None).contains("pub static RUN: Day = Day::unsolved();\n"));
+    assert!(template(Some("Rope \"Bridge\""))
+        .contains("pub static RUN: Day = Day::unsolved().titled(\"Rope \\\"Bridge\\\"\");\n"));
+}
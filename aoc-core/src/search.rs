@@ -0,0 +1,451 @@
+//! Shortest paths in graphs that are given by a function listing the edges leaving a node.
+//!
+//! [`bfs`] finds the paths with the fewest edges, [`dijkstra`] and [`astar`] those with the lowest
+//! total cost, and [`all_pairs`] the cheapest paths between all nodes of a small graph. The
+//! searches start from any number of nodes and stop at the first node accepted as a target. How
+//! each node was reached is recorded in a [`NodeMap`]: a [`HashNodes`] for any nodes, or a faster
+//! [`DenseNodes`] for nodes that can be numbered.
+
+use std::{
+    cmp::Ordering,
+    collections::{hash_map::Entry, BinaryHeap, VecDeque},
+    hash::Hash,
+    marker::PhantomData,
+    ops::Add,
+};
+
+use rustc_hash::FxHashMap;
+
+/// The edges of a graph. Implemented by closures `|node: &N, out: &mut Vec<E>| ...`.
+pub trait Successors<N, E> {
+    /// Push the edges leaving `node` to `out`, which is empty.
+    fn successors(&mut self, node: &N, out: &mut Vec<E>);
+}
+
+impl<N, E, F: FnMut(&N, &mut Vec<E>)> Successors<N, E> for F {
+    fn successors(&mut self, node: &N, out: &mut Vec<E>) {
+        self(node, out)
+    }
+}
+
+/// The cost of edges and paths, with `Default::default()` as zero.
+pub trait Cost: Copy + Ord + Add<Output = Self> + Default {}
+
+impl<C: Copy + Ord + Add<Output = C> + Default> Cost for C {}
+
+/// How a node was first reached.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Visit<N, C> {
+    /// The cost of the path from a start.
+    pub cost: C,
+    /// The node before it on the path, `None` for starts.
+    pub predecessor: Option<N>,
+}
+
+/// The visits of the nodes during a search.
+pub trait NodeMap<N, C> {
+    fn get(&self, node: &N) -> Option<&Visit<N, C>>;
+
+    /// Record the visit of a node, unless it was visited before. Returns whether it was recorded.
+    fn insert(&mut self, node: N, visit: Visit<N, C>) -> bool;
+}
+
+/// Visits kept in a hash map.
+#[derive(Debug, Clone)]
+pub struct HashNodes<N, C>(FxHashMap<N, Visit<N, C>>);
+
+impl<N, C> Default for HashNodes<N, C> {
+    fn default() -> Self {
+        Self(FxHashMap::default())
+    }
+}
+
+impl<N: Hash + Eq, C> NodeMap<N, C> for HashNodes<N, C> {
+    fn get(&self, node: &N) -> Option<&Visit<N, C>> {
+        self.0.get(node)
+    }
+
+    fn insert(&mut self, node: N, visit: Visit<N, C>) -> bool {
+        match self.0.entry(node) {
+            Entry::Occupied(_) => false,
+            Entry::Vacant(entry) => {
+                entry.insert(visit);
+                true
+            }
+        }
+    }
+}
+
+/// Visits kept in an array, for nodes that `index` numbers from 0 to `len - 1`.
+#[derive(Debug, Clone)]
+pub struct DenseNodes<N, C, F> {
+    visits: Vec<Option<Visit<N, C>>>,
+    index: F,
+}
+
+impl<N, C, F: Fn(&N) -> usize> DenseNodes<N, C, F> {
+    pub fn new(len: usize, index: F) -> Self {
+        Self {
+            visits: std::iter::repeat_with(|| None).take(len).collect(),
+            index,
+        }
+    }
+}
+
+impl<N, C, F: Fn(&N) -> usize> NodeMap<N, C> for DenseNodes<N, C, F> {
+    fn get(&self, node: &N) -> Option<&Visit<N, C>> {
+        self.visits[(self.index)(node)].as_ref()
+    }
+
+    fn insert(&mut self, node: N, visit: Visit<N, C>) -> bool {
+        let slot = &mut self.visits[(self.index)(&node)];
+        if slot.is_some() {
+            return false;
+        }
+        *slot = Some(visit);
+        true
+    }
+}
+
+/// The outcome of a search: the target it stopped at, if any, and how the nodes were reached.
+#[derive(Debug, Clone)]
+pub struct Reached<N, C, M> {
+    pub nodes: M,
+    target: Option<N>,
+    cost: PhantomData<C>,
+}
+
+impl<N: Clone, C: Copy, M: NodeMap<N, C>> Reached<N, C, M> {
+    fn new(nodes: M, target: Option<N>) -> Self {
+        Self {
+            nodes,
+            target,
+            cost: PhantomData,
+        }
+    }
+
+    pub fn target(&self) -> Option<&N> {
+        self.target.as_ref()
+    }
+
+    pub fn target_cost(&self) -> Option<C> {
+        self.cost(self.target.as_ref()?)
+    }
+
+    /// The nodes from a start to the target.
+    pub fn target_path(&self) -> Option<Vec<N>> {
+        self.path(self.target.as_ref()?)
+    }
+
+    /// The cost of the cheapest path to `node`, if it was reached. Only nodes that were expanded
+    /// before the search stopped are known to be reached by a cheapest path.
+    pub fn cost(&self, node: &N) -> Option<C> {
+        self.nodes.get(node).map(|visit| visit.cost)
+    }
+
+    /// The nodes from a start to `node`, if it was reached.
+    pub fn path(&self, node: &N) -> Option<Vec<N>> {
+        let mut path = vec![node.clone()];
+        let mut visit = self.nodes.get(node)?;
+        while let Some(predecessor) = &visit.predecessor {
+            path.push(predecessor.clone());
+            visit = self
+                .nodes
+                .get(predecessor)
+                .expect("predecessors are visited");
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Breadth-first search, where every edge costs 1.
+pub fn bfs<N: Clone, M: NodeMap<N, usize>>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl Successors<N, N>,
+    mut is_target: impl FnMut(&N) -> bool,
+    mut nodes: M,
+) -> Reached<N, usize, M> {
+    let mut queue = VecDeque::new();
+    for start in starts {
+        let visit = Visit {
+            cost: 0,
+            predecessor: None,
+        };
+        if nodes.insert(start.clone(), visit) {
+            queue.push_back((start, 0));
+        }
+    }
+    let mut edges = Vec::new();
+    while let Some((node, cost)) = queue.pop_front() {
+        if is_target(&node) {
+            return Reached::new(nodes, Some(node));
+        }
+        successors.successors(&node, &mut edges);
+        for next in edges.drain(..) {
+            let visit = Visit {
+                cost: cost + 1,
+                predecessor: Some(node.clone()),
+            };
+            if nodes.insert(next.clone(), visit) {
+                queue.push_back((next, cost + 1));
+            }
+        }
+    }
+    Reached::new(nodes, None)
+}
+
+/// Dijkstra's algorithm, for edges with non-negative costs.
+pub fn dijkstra<N: Clone, C: Cost, M: NodeMap<N, C>>(
+    starts: impl IntoIterator<Item = N>,
+    successors: impl Successors<N, (N, C)>,
+    is_target: impl FnMut(&N) -> bool,
+    nodes: M,
+) -> Reached<N, C, M> {
+    astar(starts, successors, |_| C::default(), is_target, nodes)
+}
+
+/// A* search. The `heuristic` estimates the cost from a node to the nearest target and must not
+/// overestimate it, nor decrease by more than the cost of an edge along it.
+pub fn astar<N: Clone, C: Cost, M: NodeMap<N, C>>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl Successors<N, (N, C)>,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_target: impl FnMut(&N) -> bool,
+    mut nodes: M,
+) -> Reached<N, C, M> {
+    let mut open = BinaryHeap::new();
+    let mut pushed = 0;
+    for start in starts {
+        open.push(Open {
+            estimate: heuristic(&start),
+            cost: C::default(),
+            order: pushed,
+            node: start,
+            predecessor: None,
+        });
+        pushed += 1;
+    }
+    let mut edges = Vec::new();
+    while let Some(current) = open.pop() {
+        let visit = Visit {
+            cost: current.cost,
+            predecessor: current.predecessor,
+        };
+        if !nodes.insert(current.node.clone(), visit) {
+            continue;
+        }
+        if is_target(&current.node) {
+            return Reached::new(nodes, Some(current.node));
+        }
+        successors.successors(&current.node, &mut edges);
+        for (next, edge_cost) in edges.drain(..) {
+            if nodes.get(&next).is_none() {
+                let cost = current.cost + edge_cost;
+                open.push(Open {
+                    estimate: cost + heuristic(&next),
+                    cost,
+                    order: pushed,
+                    node: next,
+                    predecessor: Some(current.node.clone()),
+                });
+                pushed += 1;
+            }
+        }
+    }
+    Reached::new(nodes, None)
+}
+
+/// A node waiting to be expanded by [`astar`].
+struct Open<N, C> {
+    estimate: C,
+    cost: C,
+    order: usize,
+    node: N,
+    predecessor: Option<N>,
+}
+
+/// The lowest estimate comes first. Among equal estimates, the node furthest along its path comes
+/// first, and after that the one pushed first.
+impl<N, C: Ord> Ord for Open<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .estimate
+            .cmp(&self.estimate)
+            .then_with(|| self.cost.cmp(&other.cost))
+            .then_with(|| other.order.cmp(&self.order))
+    }
+}
+
+impl<N, C: Ord> PartialOrd for Open<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> PartialEq for Open<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<N, C: Ord> Eq for Open<N, C> {}
+
+/// The cheapest paths between all pairs of nodes `0..len`, by the Floyd-Warshall algorithm.
+pub fn all_pairs<C: Cost>(
+    len: usize,
+    mut successors: impl Successors<usize, (usize, C)>,
+) -> AllPairs<C> {
+    let mut paths = AllPairs {
+        len,
+        costs: vec![None; len * len],
+        next: vec![0; len * len],
+    };
+    let mut edges = Vec::new();
+    for from in 0..len {
+        paths.costs[from * len + from] = Some(C::default());
+        paths.next[from * len + from] = from;
+        successors.successors(&from, &mut edges);
+        for (to, cost) in edges.drain(..) {
+            let index = from * len + to;
+            if paths.costs[index].map_or(true, |known| cost < known) {
+                paths.costs[index] = Some(cost);
+                paths.next[index] = to;
+            }
+        }
+    }
+    for via in 0..len {
+        for from in 0..len {
+            let first = match paths.costs[from * len + via] {
+                Some(cost) => cost,
+                None => continue,
+            };
+            for to in 0..len {
+                if let Some(second) = paths.costs[via * len + to] {
+                    let index = from * len + to;
+                    let cost = first + second;
+                    if paths.costs[index].map_or(true, |known| cost < known) {
+                        paths.costs[index] = Some(cost);
+                        paths.next[index] = paths.next[from * len + via];
+                    }
+                }
+            }
+        }
+    }
+    paths
+}
+
+/// The result of [`all_pairs`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AllPairs<C> {
+    len: usize,
+    costs: Vec<Option<C>>,
+    /// The node after `from` on the path to `to`, at `from * len + to`.
+    next: Vec<usize>,
+}
+
+impl<C: Copy> AllPairs<C> {
+    /// The cost of the cheapest path, or `None` if there is none.
+    pub fn cost(&self, from: usize, to: usize) -> Option<C> {
+        self.costs[from * self.len + to]
+    }
+
+    /// The nodes on the cheapest path, including `from` and `to`.
+    pub fn path(&self, from: usize, to: usize) -> Option<Vec<usize>> {
+        self.cost(from, to)?;
+        let mut path = vec![from];
+        let mut current = from;
+        while current != to {
+            current = self.next[current * self.len + to];
+            path.push(current);
+        }
+        Some(path)
+    }
+}
+
+/// A 5x5 grid where moving onto a cell costs its digit, and 0 is a wall.
+#[cfg(test)]
+fn test_graph() -> impl Fn(&usize, &mut Vec<(usize, u32)>) {
+    let costs = b"11111\
+                  90001\
+                  11101\
+                  10001\
+                  11111"
+        .iter()
+        .map(|&b| u32::from(b - b'0'))
+        .collect::<Vec<_>>();
+    move |&node: &usize, out: &mut Vec<(usize, u32)>| {
+        let (x, y) = (node % 5, node / 5);
+        let neighbours = [
+            (x > 0).then(|| node - 1),
+            (x < 4).then(|| node + 1),
+            (y > 0).then(|| node - 5),
+            (y < 4).then(|| node + 5),
+        ];
+        out.extend(
+            neighbours
+                .into_iter()
+                .flatten()
+                .filter(|&next| costs[next] != 0)
+                .map(|next| (next, costs[next])),
+        );
+    }
+}
+
+#[test]
+fn test_bfs() {
+    let successors = test_graph();
+    let unit = |node: &usize, out: &mut Vec<usize>| {
+        let mut edges = Vec::new();
+        successors(node, &mut edges);
+        out.extend(edges.into_iter().map(|(next, _)| next));
+    };
+    let reached = bfs([0], unit, |&node| node == 20, HashNodes::default());
+    assert_eq!(reached.target_cost(), Some(4));
+    assert_eq!(reached.target_path(), Some(vec![0, 5, 10, 15, 20]));
+
+    let reached = bfs([0], unit, |&node| node == 6, DenseNodes::new(25, |&n| n));
+    assert_eq!(reached.target(), None);
+    assert_eq!(reached.cost(&24), Some(8));
+    assert_eq!(reached.cost(&12), Some(4));
+    assert_eq!(reached.cost(&6), None);
+
+    let reached = bfs([0, 24], unit, |&node| node == 14, HashNodes::default());
+    assert_eq!(reached.target_path(), Some(vec![24, 19, 14]));
+}
+
+#[test]
+fn test_dijkstra_astar() {
+    let successors = test_graph();
+    let reached = dijkstra([0], &successors, |&node| node == 12, HashNodes::default());
+    assert_eq!(reached.target_cost(), Some(12));
+    assert_eq!(reached.target_path(), Some(vec![0, 5, 10, 11, 12]));
+
+    let reached = dijkstra([0], &successors, |&node| node == 15, HashNodes::default());
+    assert_eq!(reached.target_cost(), Some(11));
+
+    let manhattan = |&node: &usize| ((node % 5).abs_diff(2) + (node / 5).abs_diff(2)) as u32;
+    let nodes = DenseNodes::new(25, |&n| n);
+    let reached = astar([0], &successors, manhattan, |&node| node == 12, nodes);
+    assert_eq!(reached.target_cost(), Some(12));
+    assert_eq!(reached.target_path(), Some(vec![0, 5, 10, 11, 12]));
+}
+
+#[test]
+fn test_all_pairs() {
+    let successors = test_graph();
+    let paths = all_pairs(25, successors);
+    assert_eq!(paths.cost(0, 5), Some(9));
+    assert_eq!(paths.cost(5, 0), Some(1));
+    assert_eq!(paths.cost(0, 15), Some(11));
+    assert_eq!(paths.cost(3, 3), Some(0));
+    assert_eq!(paths.cost(0, 6), None);
+    let path = paths.path(0, 24).unwrap();
+    let steps = path
+        .windows(2)
+        .map(|step| paths.cost(step[0], step[1]).unwrap());
+    assert_eq!(steps.sum::<u32>(), 8);
+    assert_eq!(paths.cost(0, 24), Some(8));
+    assert_eq!(paths.path(0, 6), None);
+    assert_eq!(paths.path(7, 7), Some(vec![7]));
+}
@@ -0,0 +1,132 @@
+//! Summary statistics over the timings of repeated runs.
+
+use std::{fmt, time::Duration};
+
+use crate::report::millis;
+
+/// The durations of all repetitions of a part, in the order they were run.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Timings {
+    samples: Vec<Duration>,
+}
+
+impl Timings {
+    pub fn from_samples(samples: Vec<Duration>) -> Self {
+        Self { samples }
+    }
+
+    pub fn record(&mut self, duration: Duration) {
+        self.samples.push(duration);
+    }
+
+    pub fn samples(&self) -> &[Duration] {
+        &self.samples
+    }
+
+    pub fn runs(&self) -> usize {
+        self.samples.len()
+    }
+
+    pub fn total(&self) -> Duration {
+        self.samples.iter().sum()
+    }
+
+    pub fn min(&self) -> Duration {
+        self.samples.iter().copied().min().unwrap_or_default()
+    }
+
+    pub fn max(&self) -> Duration {
+        self.samples.iter().copied().max().unwrap_or_default()
+    }
+
+    pub fn mean(&self) -> Duration {
+        if self.samples.is_empty() {
+            Duration::ZERO
+        } else {
+            self.total() / self.samples.len() as u32
+        }
+    }
+
+    pub fn median(&self) -> Duration {
+        let sorted = self.sorted();
+        match sorted.len() {
+            0 => Duration::ZERO,
+            n if n % 2 == 1 => sorted[n / 2],
+            n => (sorted[n / 2 - 1] + sorted[n / 2]) / 2,
+        }
+    }
+
+    /// The `p`th percentile (0 to 100) using the nearest-rank method.
+    pub fn percentile(&self, p: f64) -> Duration {
+        let sorted = self.sorted();
+        if sorted.is_empty() {
+            return Duration::ZERO;
+        }
+        let rank = (p / 100.0 * sorted.len() as f64).ceil() as usize;
+        sorted[rank.clamp(1, sorted.len()) - 1]
+    }
+
+    /// The sample standard deviation, which is zero for fewer than two runs.
+    pub fn stddev(&self) -> Duration {
+        if self.samples.len() < 2 {
+            return Duration::ZERO;
+        }
+        let mean = self.mean().as_secs_f64();
+        let sum_squares: f64 = self
+            .samples
+            .iter()
+            .map(|sample| (sample.as_secs_f64() - mean).powi(2))
+            .sum();
+        Duration::from_secs_f64((sum_squares / (self.samples.len() - 1) as f64).sqrt())
+    }
+
+    fn sorted(&self) -> Vec<Duration> {
+        let mut sorted = self.samples.clone();
+        sorted.sort_unstable();
+        sorted
+    }
+}
+
+impl fmt::Display for Timings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} runs: min {:.3} ms, median {:.3} ms, mean {:.3} ms, p95 {:.3} ms, max {:.3} ms, stddev {:.3} ms",
+            self.runs(),
+            millis(self.min()),
+            millis(self.median()),
+            millis(self.mean()),
+            millis(self.percentile(95.0)),
+            millis(self.max()),
+            millis(self.stddev()),
+        )
+    }
+}
+
+#[test]
+fn test_timings() {
+    let timings = Timings::from_samples(
+        [4, 1, 3, 2, 10]
+            .into_iter()
+            .map(Duration::from_millis)
+            .collect(),
+    );
+    assert_eq!(timings.runs(), 5);
+    assert_eq!(timings.total(), Duration::from_millis(20));
+    assert_eq!(timings.min(), Duration::from_millis(1));
+    assert_eq!(timings.max(), Duration::from_millis(10));
+    assert_eq!(timings.mean(), Duration::from_millis(4));
+    assert_eq!(timings.median(), Duration::from_millis(3));
+    assert_eq!(timings.percentile(95.0), Duration::from_millis(10));
+    assert_eq!(timings.percentile(40.0), Duration::from_millis(2));
+    // sqrt((0 + 9 + 1 + 4 + 36) / 4) = sqrt(12.5)
+    assert_eq!(timings.stddev().as_micros(), 3535);
+
+    let timings = Timings::from_samples(vec![Duration::from_millis(1), Duration::from_millis(2)]);
+    assert_eq!(timings.median(), Duration::from_micros(1500));
+
+    let timings = Timings::default();
+    assert_eq!(timings.median(), Duration::ZERO);
+    assert_eq!(timings.percentile(95.0), Duration::ZERO);
+    assert_eq!(timings.stddev(), Duration::ZERO);
+}
@@ -0,0 +1,435 @@
+//! The log of answers submitted to the website, stored next to the input as `<name>.submissions`.
+//!
+//! Every attempt is one line with the time in seconds since the Unix epoch, the part, the response
+//! and the answer, e.g.
+//!
+//! ```text
+//! 1670236800 part1 too_high 1234
+//! 1670236850 part1 rate_limited:37 1000
+//! 1670236900 part1 correct 1000
+//! ```
+//!
+//! Before submitting, the log is consulted so that an answer that is known to be wrong, or that
+//! is known to be too high or too low, is never sent again.
+
+use std::{
+    fmt,
+    io::Write,
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::Context;
+
+use crate::{answer::Answer, runner::Part};
+
+/// How the website judged a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Response {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// The answer was not checked because the previous one was submitted too recently. Contains
+    /// the time left to wait, if the website said so.
+    RateLimited(Option<Duration>),
+    /// The part is not the one to be solved next, usually because it was solved already.
+    WrongLevel,
+}
+
+impl Response {
+    /// Interpret the HTML page the website returns after submitting an answer.
+    pub fn parse_page(page: &str) -> anyhow::Result<Self> {
+        if page.contains("That's the right answer") {
+            Ok(Response::Correct)
+        } else if page.contains("That's not the right answer") {
+            if page.contains("your answer is too high") {
+                Ok(Response::TooHigh)
+            } else if page.contains("your answer is too low") {
+                Ok(Response::TooLow)
+            } else {
+                Ok(Response::Wrong)
+            }
+        } else if page.contains("You gave an answer too recently") {
+            Ok(Response::RateLimited(parse_wait(page)))
+        } else if page.contains("You don't seem to be solving the right level") {
+            Ok(Response::WrongLevel)
+        } else {
+            anyhow::bail!("unexpected response from the website: {}", excerpt(page))
+        }
+    }
+
+    pub fn is_wrong(self) -> bool {
+        matches!(self, Response::TooHigh | Response::TooLow | Response::Wrong)
+    }
+}
+
+impl fmt::Display for Response {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Response::Correct => write!(f, "correct"),
+            Response::TooHigh => write!(f, "too_high"),
+            Response::TooLow => write!(f, "too_low"),
+            Response::Wrong => write!(f, "wrong"),
+            Response::RateLimited(None) => write!(f, "rate_limited"),
+            Response::RateLimited(Some(wait)) => write!(f, "rate_limited:{}", wait.as_secs()),
+            Response::WrongLevel => write!(f, "wrong_level"),
+        }
+    }
+}
+
+impl FromStr for Response {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Response::Correct),
+            "too_high" => Ok(Response::TooHigh),
+            "too_low" => Ok(Response::TooLow),
+            "wrong" => Ok(Response::Wrong),
+            "rate_limited" => Ok(Response::RateLimited(None)),
+            "wrong_level" => Ok(Response::WrongLevel),
+            _ => {
+                let seconds = s
+                    .strip_prefix("rate_limited:")
+                    .and_then(|seconds| seconds.parse().ok())
+                    .with_context(|| format!("unknown response {}", s))?;
+                Ok(Response::RateLimited(Some(Duration::from_secs(seconds))))
+            }
+        }
+    }
+}
+
+/// Find the "You have 1m 23s left to wait" of a rate limited response.
+fn parse_wait(page: &str) -> Option<Duration> {
+    let start = page.find("You have ")? + "You have ".len();
+    let end = start + page[start..].find(" left to wait")?;
+    let mut seconds = 0;
+    for part in page[start..end].split_whitespace() {
+        let (value, unit) = part.split_at(part.find(|ch: char| !ch.is_ascii_digit())?);
+        let value: u64 = value.parse().ok()?;
+        seconds += match unit {
+            "h" => value * 3600,
+            "m" => value * 60,
+            "s" => value,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+/// The text of the `<article>` of a page, or its beginning if there is none.
+fn excerpt(page: &str) -> String {
+    let article = page
+        .split_once("<article>")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(page, |(article, _)| article);
+    let mut text = String::new();
+    let mut in_tag = false;
+    for ch in article.chars() {
+        match ch {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            ch if !in_tag => text.push(ch),
+            _ => {}
+        }
+    }
+    text.split_whitespace()
+        .take(40)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    /// Seconds since the Unix epoch.
+    pub time: u64,
+    pub part: Part,
+    pub response: Response,
+    pub answer: String,
+}
+
+impl Submission {
+    pub fn now(part: Part, response: Response, answer: &Answer) -> Self {
+        Self {
+            time: unix_time(),
+            part,
+            response,
+            answer: answer.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Submission {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} part{} {} {}",
+            self.time,
+            self.part.number(),
+            self.response,
+            self.answer
+        )
+    }
+}
+
+impl FromStr for Submission {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut fields = s.splitn(4, ' ');
+        let mut next = || {
+            fields
+                .next()
+                .context("expected `<time> <part> <response> <answer>`")
+        };
+        let time = next()?.parse().context("invalid time")?;
+        let part = next()?
+            .strip_prefix("part")
+            .and_then(|part| part.parse().ok())
+            .context("invalid part")?;
+        let response = next()?.parse()?;
+        let answer = next()?.to_owned();
+        Ok(Self {
+            time,
+            part,
+            response,
+            answer,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SubmissionLog {
+    submissions: Vec<Submission>,
+}
+
+impl SubmissionLog {
+    /// The log belonging to the given input file.
+    pub fn path_for(input: &Path) -> PathBuf {
+        input.with_extension("submissions")
+    }
+
+    /// Load the log for the given input file. A missing log means nothing was submitted yet.
+    pub fn load(input: &Path) -> anyhow::Result<Self> {
+        let path = Self::path_for(input);
+        let text = match std::fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(err) => return Err(err).with_context(|| format!("reading {}", path.display())),
+        };
+        let submissions = text
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(index, line)| {
+                line.parse()
+                    .with_context(|| format!("{}: line {}", path.display(), index + 1))
+            })
+            .collect::<anyhow::Result<_>>()?;
+        Ok(Self { submissions })
+    }
+
+    pub fn submissions(&self) -> &[Submission] {
+        &self.submissions
+    }
+
+    /// Add a submission to the log in memory and in the log file of the input.
+    pub fn append(&mut self, input: &Path, submission: Submission) -> anyhow::Result<()> {
+        let path = Self::path_for(input);
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .with_context(|| format!("opening {}", path.display()))?;
+        writeln!(file, "{}", submission).with_context(|| format!("writing {}", path.display()))?;
+        self.submissions.push(submission);
+        Ok(())
+    }
+
+    /// Check whether an answer may be submitted at the given time, explaining why not otherwise.
+    pub fn check(&self, part: Part, answer: &Answer, now: u64) -> anyhow::Result<()> {
+        let value = match answer {
+            Answer::Integer(value) => Some(i128::from(*value)),
+            Answer::BigInteger(value) => Some(*value),
+            _ => None,
+        };
+        for submission in self.submissions.iter().filter(|s| s.part == part) {
+            let previous = Answer::parse(&submission.answer);
+            let previous_value = match previous {
+                Answer::Integer(value) => Some(i128::from(value)),
+                Answer::BigInteger(value) => Some(value),
+                _ => None,
+            };
+            match submission.response {
+                Response::Correct => anyhow::bail!(
+                    "part {} was already solved with {}",
+                    part.number(),
+                    submission.answer
+                ),
+                response if response.is_wrong() && previous == *answer => anyhow::bail!(
+                    "{} was already submitted and is {}",
+                    answer,
+                    describe(response)
+                ),
+                Response::TooHigh => {
+                    if let (Some(value), Some(high)) = (value, previous_value) {
+                        anyhow::ensure!(
+                            value < high,
+                            "{} cannot be right, {} was already too high",
+                            answer,
+                            high
+                        );
+                    }
+                }
+                Response::TooLow => {
+                    if let (Some(value), Some(low)) = (value, previous_value) {
+                        anyhow::ensure!(
+                            value > low,
+                            "{} cannot be right, {} was already too low",
+                            answer,
+                            low
+                        );
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        // Waiting applies to all parts and is only known from the most recent attempt.
+        if let Some(Submission {
+            time,
+            response: Response::RateLimited(Some(wait)),
+            ..
+        }) = self.submissions.last()
+        {
+            let ready = time + wait.as_secs();
+            anyhow::ensure!(
+                now >= ready,
+                "the website asked to wait {} more seconds before submitting again",
+                ready - now
+            );
+        }
+        Ok(())
+    }
+}
+
+/// A human readable description of a response.
+pub fn describe(response: Response) -> String {
+    match response {
+        Response::Correct => "the right answer".to_owned(),
+        Response::TooHigh => "not the right answer, it is too high".to_owned(),
+        Response::TooLow => "not the right answer, it is too low".to_owned(),
+        Response::Wrong => "not the right answer".to_owned(),
+        Response::RateLimited(Some(wait)) => format!(
+            "not checked, the previous answer was given too recently (wait {} more seconds)",
+            wait.as_secs()
+        ),
+        Response::RateLimited(None) => {
+            "not checked, the previous answer was given too recently".to_owned()
+        }
+        Response::WrongLevel => "not checked, the part was already solved or is locked".to_owned(),
+    }
+}
+
+pub fn unix_time() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
+
+#[test]
+fn test_parse_page() {
+    let page = |text| format!("<main>\n<article><p>{}</p></article>\n</main>", text);
+    assert_eq!(
+        Response::parse_page(&page(
+            "That's the right answer!  You are one gold star closer."
+        ))
+        .unwrap(),
+        Response::Correct
+    );
+    assert_eq!(
+        Response::parse_page(&page(
+            "That's not the right answer; your answer is too high.  If you're stuck, ..."
+        ))
+        .unwrap(),
+        Response::TooHigh
+    );
+    assert_eq!(
+        Response::parse_page(&page("That's not the right answer.  If you're stuck, ...")).unwrap(),
+        Response::Wrong
+    );
+    assert_eq!(
+        Response::parse_page(&page(
+            "You gave an answer too recently; you have to wait after submitting an answer before \
+             trying again.  You have 1m 23s left to wait. <a href=\"/2022/day/1\">[Return]</a>"
+        ))
+        .unwrap(),
+        Response::RateLimited(Some(Duration::from_secs(83)))
+    );
+    assert_eq!(
+        Response::parse_page(&page(
+            "You don't seem to be solving the right level.  Did you already complete it?"
+        ))
+        .unwrap(),
+        Response::WrongLevel
+    );
+    let err = Response::parse_page(&page("Something <em>else</em>")).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "unexpected response from the website: Something else"
+    );
+}
+
+#[test]
+fn test_submission_log() {
+    let text = "100 part1 too_high 1234\n\
+                200 part1 wrong abc\n\
+                300 part1 too_low 1000\n\
+                400 part2 rate_limited:60 7\n";
+    let log = SubmissionLog {
+        submissions: text.lines().map(|line| line.parse().unwrap()).collect(),
+    };
+    assert_eq!(
+        log.submissions()
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<_>>(),
+        text.lines().collect::<Vec<_>>()
+    );
+
+    let check =
+        |part, answer: Answer, now| log.check(part, &answer, now).map_err(|e| e.to_string());
+    assert_eq!(check(Part::One, Answer::Integer(1100), 500), Ok(()));
+    assert_eq!(
+        check(Part::One, Answer::Integer(1234), 500),
+        Err("1234 was already submitted and is not the right answer, it is too high".to_owned())
+    );
+    assert_eq!(
+        check(Part::One, Answer::Integer(2000), 500),
+        Err("2000 cannot be right, 1234 was already too high".to_owned())
+    );
+    assert_eq!(
+        check(Part::One, Answer::Integer(999), 500),
+        Err("999 cannot be right, 1000 was already too low".to_owned())
+    );
+    assert!(check(Part::One, Answer::from("abc"), 500).is_err());
+    assert_eq!(
+        check(Part::One, Answer::Integer(1100), 430),
+        Err("the website asked to wait 30 more seconds before submitting again".to_owned())
+    );
+
+    let mut solved = log.clone();
+    solved
+        .submissions
+        .push("600 part1 correct 1100".parse().unwrap());
+    assert_eq!(
+        solved
+            .check(Part::One, &Answer::Integer(1100), 700)
+            .unwrap_err()
+            .to_string(),
+        "part 1 was already solved with 1100"
+    );
+    assert!(solved.check(Part::Two, &Answer::Integer(8), 700).is_ok());
+}
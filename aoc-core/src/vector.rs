@@ -0,0 +1,407 @@
+//! Points and vectors in two and three dimensions, the 24 rotations of a cube and axis-aligned
+//! bounding boxes.
+
+use std::{
+    fmt::Debug,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+/// The signed integers that vectors are made of.
+pub trait Scalar:
+    Copy
+    + Ord
+    + Default
+    + Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn abs(self) -> Self;
+}
+
+macro_rules! impl_scalar {
+    ($($t:ty),*) => {
+        $(
+            impl Scalar for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn abs(self) -> Self {
+                    <$t>::abs(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar!(i32, i64);
+
+/// The operations of [`Vec2`] and [`Vec3`] needed by [`Aabb`].
+pub trait Vector: Copy + PartialEq + Add<Output = Self> + Sub<Output = Self> {
+    type Scalar: Scalar;
+
+    /// A vector with all components set to `value`.
+    fn splat(value: Self::Scalar) -> Self;
+
+    /// The smaller of each pair of components, unlike the lexicographic [`Ord::min`].
+    fn component_min(self, other: Self) -> Self;
+
+    /// The larger of each pair of components, unlike the lexicographic [`Ord::max`].
+    fn component_max(self, other: Self) -> Self;
+
+    /// Whether every component is less than or equal to the one of `other`.
+    fn all_le(self, other: Self) -> bool;
+
+    /// The product of the components.
+    fn product(self) -> Self::Scalar;
+}
+
+macro_rules! vector {
+    ($name:ident { $($field:ident),* }) => {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+        pub struct $name<T> {
+            $(pub $field: T,)*
+        }
+
+        impl<T> $name<T> {
+            pub const fn new($($field: T),*) -> Self {
+                Self { $($field),* }
+            }
+
+            pub fn zip_with<S, R>(self, other: $name<S>, mut f: impl FnMut(T, S) -> R) -> $name<R> {
+                $name { $($field: f(self.$field, other.$field)),* }
+            }
+
+            pub fn map<R>(self, mut f: impl FnMut(T) -> R) -> $name<R> {
+                $name { $($field: f(self.$field)),* }
+            }
+
+            pub fn all(self, mut f: impl FnMut(&T) -> bool) -> bool {
+                true $(&& f(&self.$field))*
+            }
+
+            pub fn any(self, mut f: impl FnMut(&T) -> bool) -> bool {
+                false $(|| f(&self.$field))*
+            }
+        }
+
+        impl<T: Scalar> $name<T> {
+            /// The sum of the absolute differences of the components.
+            pub fn manhattan(self, other: Self) -> T {
+                T::ZERO $(+ (self.$field - other.$field).abs())*
+            }
+
+            /// The largest absolute difference of the components, i.e. the number of king moves
+            /// between the points.
+            pub fn chebyshev(self, other: Self) -> T {
+                T::ZERO $(.max((self.$field - other.$field).abs()))*
+            }
+
+            pub fn dot(self, other: Self) -> T {
+                T::ZERO $(+ self.$field * other.$field)*
+            }
+
+            /// Every component replaced by -1, 0 or 1 according to its sign.
+            pub fn signum(self) -> Self {
+                self.map(|value| match value.cmp(&T::ZERO) {
+                    std::cmp::Ordering::Less => -T::ONE,
+                    std::cmp::Ordering::Equal => T::ZERO,
+                    std::cmp::Ordering::Greater => T::ONE,
+                })
+            }
+        }
+
+        impl<T: Scalar> Vector for $name<T> {
+            type Scalar = T;
+
+            fn splat(value: T) -> Self {
+                Self { $($field: value),* }
+            }
+
+            fn component_min(self, other: Self) -> Self {
+                self.zip_with(other, Ord::min)
+            }
+
+            fn component_max(self, other: Self) -> Self {
+                self.zip_with(other, Ord::max)
+            }
+
+            fn all_le(self, other: Self) -> bool {
+                true $(&& self.$field <= other.$field)*
+            }
+
+            fn product(self) -> T {
+                T::ONE $(* self.$field)*
+            }
+        }
+
+        impl<T: Add<T>> Add<$name<T>> for $name<T> {
+            type Output = $name<T::Output>;
+
+            fn add(self, rhs: $name<T>) -> Self::Output {
+                self.zip_with(rhs, Add::add)
+            }
+        }
+
+        impl<T: Sub<T>> Sub<$name<T>> for $name<T> {
+            type Output = $name<T::Output>;
+
+            fn sub(self, rhs: $name<T>) -> Self::Output {
+                self.zip_with(rhs, Sub::sub)
+            }
+        }
+
+        impl<T: AddAssign<T>> AddAssign<$name<T>> for $name<T> {
+            fn add_assign(&mut self, rhs: $name<T>) {
+                $(self.$field += rhs.$field;)*
+            }
+        }
+
+        impl<T: SubAssign<T>> SubAssign<$name<T>> for $name<T> {
+            fn sub_assign(&mut self, rhs: $name<T>) {
+                $(self.$field -= rhs.$field;)*
+            }
+        }
+
+        impl<T: Neg> Neg for $name<T> {
+            type Output = $name<T::Output>;
+
+            fn neg(self) -> Self::Output {
+                self.map(Neg::neg)
+            }
+        }
+
+        /// Scaling by a scalar.
+        impl<T: Mul<T> + Copy> Mul<T> for $name<T> {
+            type Output = $name<T::Output>;
+
+            fn mul(self, rhs: T) -> Self::Output {
+                self.map(|value| value * rhs)
+            }
+        }
+    };
+}
+
+vector!(Vec2 { x, y });
+vector!(Vec3 { x, y, z });
+
+impl<T> Vec3<T> {
+    pub fn from_array([x, y, z]: [T; 3]) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn to_array(self) -> [T; 3] {
+        [self.x, self.y, self.z]
+    }
+}
+
+/// A rotation of 3D space that maps the axes onto axes, i.e. one of the 24 ways to turn a cube.
+///
+/// As a matrix, row `i` has a single non-zero entry `signs[i]` in column `axes[i]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rotation {
+    axes: [u8; 3],
+    signs: [i8; 3],
+}
+
+/// All rotations, starting with the identity.
+pub const ROTATIONS: [Rotation; 24] = Rotation::all();
+
+impl Rotation {
+    pub const IDENTITY: Rotation = Rotation {
+        axes: [0, 1, 2],
+        signs: [1, 1, 1],
+    };
+
+    /// The signed permutation matrices with determinant 1.
+    const fn all() -> [Rotation; 24] {
+        const PERMUTATIONS: [([u8; 3], i8); 6] = [
+            ([0, 1, 2], 1),
+            ([0, 2, 1], -1),
+            ([1, 0, 2], -1),
+            ([1, 2, 0], 1),
+            ([2, 0, 1], 1),
+            ([2, 1, 0], -1),
+        ];
+        let mut rotations = [Rotation::IDENTITY; 24];
+        let mut count = 0;
+        let mut p = 0;
+        while p < PERMUTATIONS.len() {
+            let (axes, parity) = PERMUTATIONS[p];
+            let mut bits = 0;
+            while bits < 8 {
+                let signs = [sign(bits, 4), sign(bits, 2), sign(bits, 1)];
+                if signs[0] * signs[1] * signs[2] * parity == 1 {
+                    rotations[count] = Rotation { axes, signs };
+                    count += 1;
+                }
+                bits += 1;
+            }
+            p += 1;
+        }
+        rotations
+    }
+
+    #[inline]
+    pub fn apply<T: Scalar>(self, v: Vec3<T>) -> Vec3<T> {
+        let components = v.to_array();
+        let component = |i: usize| {
+            let value = components[self.axes[i] as usize];
+            if self.signs[i] < 0 {
+                -value
+            } else {
+                value
+            }
+        };
+        Vec3::new(component(0), component(1), component(2))
+    }
+
+    /// The rotation that applies `self` first and then `next`.
+    pub fn then(self, next: Rotation) -> Rotation {
+        Rotation {
+            axes: next.axes.map(|axis| self.axes[axis as usize]),
+            signs: [0, 1, 2].map(|i| next.signs[i] * self.signs[next.axes[i] as usize]),
+        }
+    }
+
+    pub fn inverse(self) -> Rotation {
+        let mut inverse = Rotation::IDENTITY;
+        for i in 0..3 {
+            let axis = self.axes[i] as usize;
+            inverse.axes[axis] = i as u8;
+            inverse.signs[axis] = self.signs[i];
+        }
+        inverse
+    }
+
+    pub fn matrix(self) -> [[i32; 3]; 3] {
+        let mut matrix = [[0; 3]; 3];
+        for (i, row) in matrix.iter_mut().enumerate() {
+            row[self.axes[i] as usize] = self.signs[i].into();
+        }
+        matrix
+    }
+}
+
+const fn sign(bits: u8, bit: u8) -> i8 {
+    if bits & bit == 0 {
+        1
+    } else {
+        -1
+    }
+}
+
+/// An axis-aligned bounding box, including `min` and `max`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Aabb<V> {
+    pub min: V,
+    pub max: V,
+}
+
+impl<V: Vector> Aabb<V> {
+    /// The box containing only `point`.
+    pub fn new(point: V) -> Self {
+        Self {
+            min: point,
+            max: point,
+        }
+    }
+
+    /// The smallest box containing all points, or `None` if there are none.
+    pub fn from_points(points: impl IntoIterator<Item = V>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let mut aabb = Self::new(points.next()?);
+        points.for_each(|point| aabb.extend(point));
+        Some(aabb)
+    }
+
+    pub fn extend(&mut self, point: V) {
+        self.min = self.min.component_min(point);
+        self.max = self.max.component_max(point);
+    }
+
+    pub fn contains(&self, point: V) -> bool {
+        self.min.all_le(point) && point.all_le(self.max)
+    }
+
+    /// The number of points along each axis.
+    pub fn size(&self) -> V {
+        self.max - self.min + V::splat(V::Scalar::ONE)
+    }
+
+    /// The number of points in the box.
+    pub fn volume(&self) -> V::Scalar {
+        self.size().product()
+    }
+
+    /// The box extended by `by` in every direction.
+    pub fn grow(&self, by: V::Scalar) -> Self {
+        Self {
+            min: self.min - V::splat(by),
+            max: self.max + V::splat(by),
+        }
+    }
+}
+
+#[test]
+fn test_arithmetic() {
+    let a = Vec3::new(1, -2, 3);
+    let b = Vec3::new(4, 0, -1);
+    assert_eq!(a + b, Vec3::new(5, -2, 2));
+    assert_eq!(a - b, Vec3::new(-3, -2, 4));
+    assert_eq!(-a * 2, Vec3::new(-2, 4, -6));
+    assert_eq!(a.manhattan(b), 9);
+    assert_eq!(a.chebyshev(b), 4);
+    assert_eq!(a.dot(b), 1);
+    assert_eq!((b - a).signum(), Vec3::new(1, 1, -1));
+    assert_eq!(a.component_min(b), Vec3::new(1, -2, -1));
+    assert_eq!(a.component_max(b), Vec3::new(4, 0, 3));
+    assert_eq!(a.min(b), a);
+    let mut c = Vec2::new(1i64, 1);
+    c += Vec2::new(2, 3);
+    c -= Vec2::new(1, 0);
+    assert_eq!(c, Vec2::new(2, 4));
+    assert_eq!(c.chebyshev(Vec2::default()), 4);
+}
+
+#[test]
+fn test_rotations() {
+    let v = Vec3::new(1, 2, 3);
+    let mut images = ROTATIONS.map(|rotation| rotation.apply(v)).to_vec();
+    assert_eq!(images[0], v);
+    images.sort();
+    images.dedup();
+    assert_eq!(images.len(), 24);
+
+    for a in ROTATIONS {
+        let m = a.matrix();
+        let det = m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0]);
+        assert_eq!(det, 1);
+        assert_eq!(a.then(a.inverse()), Rotation::IDENTITY);
+        for b in ROTATIONS {
+            let both = a.then(b);
+            assert!(ROTATIONS.contains(&both));
+            assert_eq!(both.apply(v), b.apply(a.apply(v)));
+        }
+    }
+}
+
+#[test]
+fn test_aabb() {
+    let points = [Vec2::new(1, 5), Vec2::new(-2, 3), Vec2::new(0, 7)];
+    let aabb = Aabb::from_points(points).unwrap();
+    assert_eq!(aabb.min, Vec2::new(-2, 3));
+    assert_eq!(aabb.max, Vec2::new(1, 7));
+    assert_eq!(aabb.size(), Vec2::new(4, 5));
+    assert_eq!(aabb.volume(), 20);
+    assert!(aabb.contains(Vec2::new(0, 3)));
+    assert!(!aabb.contains(Vec2::new(2, 3)));
+    assert!(aabb.grow(1).contains(Vec2::new(2, 2)));
+    assert_eq!(Aabb::<Vec3<i64>>::from_points([]), None);
+}
@@ -0,0 +1,221 @@
+//! Downloading puzzle inputs from the Advent of Code website and submitting answers, or any
+//! server that behaves like it (see [`Config::base_url`]).
+
+use std::path::Path;
+
+use anyhow::Context;
+
+use crate::{config::Config, runner::Part, submissions::Response};
+
+const USER_AGENT: &str = concat!(
+    "aoc-core/",
+    env!("CARGO_PKG_VERSION"),
+    " (advent-of-code solutions runner)"
+);
+
+/// What [`fetch_input`] did.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fetched {
+    Downloaded,
+    /// The input file already existed, so nothing was downloaded.
+    Cached,
+}
+
+/// Download the input of a day to `path`, unless that file already exists. Inputs never change,
+/// so there is no reason to ask the server twice.
+pub fn fetch_input(config: &Config, year: u32, day: u32, path: &Path) -> anyhow::Result<Fetched> {
+    if path.exists() {
+        return Ok(Fetched::Cached);
+    }
+    let session = config.session()?;
+    let url = format!(
+        "{}/{}/day/{}/input",
+        config.base_url.trim_end_matches('/'),
+        year,
+        day
+    );
+    let response = ureq::get(&url)
+        .set("User-Agent", USER_AGENT)
+        .set("Cookie", &format!("session={}", session))
+        .call()
+        .map_err(|err| match err {
+            ureq::Error::Status(404, _) => {
+                anyhow::anyhow!("the input of {} day {} is not available yet", year, day)
+            }
+            ureq::Error::Status(code @ (400 | 500), response) => anyhow::anyhow!(
+                "the server rejected the session token ({}: {})",
+                code,
+                response.into_string().unwrap_or_default().trim()
+            ),
+            err => anyhow::Error::new(err),
+        })
+        .with_context(|| format!("downloading {}", url))?;
+    let contents = response
+        .into_string()
+        .with_context(|| format!("downloading {}", url))?;
+
+    // Write to a temporary file first, so that an interrupted download is not taken for a cached
+    // input the next time.
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).with_context(|| format!("creating {}", dir.display()))?;
+    }
+    let partial = path.with_extension("txt.part");
+    std::fs::write(&partial, contents).with_context(|| format!("writing {}", partial.display()))?;
+    std::fs::rename(&partial, path).with_context(|| format!("writing {}", path.display()))?;
+    Ok(Fetched::Downloaded)
+}
+
+/// Submit the answer of a part and return how the website judged it.
+pub fn submit_answer(
+    config: &Config,
+    year: u32,
+    day: u32,
+    part: Part,
+    answer: &str,
+) -> anyhow::Result<Response> {
+    let session = config.session()?;
+    let url = format!(
+        "{}/{}/day/{}/answer",
+        config.base_url.trim_end_matches('/'),
+        year,
+        day
+    );
+    let level = part.number().to_string();
+    let page = ureq::post(&url)
+        .set("User-Agent", USER_AGENT)
+        .set("Cookie", &format!("session={}", session))
+        .send_form(&[("level", &level), ("answer", answer)])
+        .map_err(|err| match err {
+            ureq::Error::Status(code, response) => anyhow::anyhow!(
+                "the server responded with {}: {}",
+                code,
+                response.into_string().unwrap_or_default().trim()
+            ),
+            err => anyhow::Error::new(err),
+        })
+        .and_then(|response| Ok(response.into_string()?))
+        .with_context(|| format!("submitting to {}", url))?;
+    Response::parse_page(&page)
+}
+
+/// Serve the given responses, one per connection, and return the requests that were received.
+#[cfg(test)]
+pub(crate) fn stub_server(
+    responses: Vec<String>,
+) -> (String, std::thread::JoinHandle<Vec<String>>) {
+    use std::io::{BufRead, BufReader, Read, Write};
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let server = std::thread::spawn(move || {
+        let mut requests = Vec::new();
+        for response in responses {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request = String::new();
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some((name, value)) = line.split_once(':') {
+                    if name.eq_ignore_ascii_case("content-length") {
+                        content_length = value.trim().parse().unwrap();
+                    }
+                }
+                request.push_str(&line);
+                if line == "\r\n" || line.is_empty() {
+                    break;
+                }
+            }
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).unwrap();
+            request.push_str(&String::from_utf8(body).unwrap());
+            requests.push(request);
+            reader.into_inner().write_all(response.as_bytes()).unwrap();
+        }
+        requests
+    });
+    (base_url, server)
+}
+
+#[cfg(test)]
+pub(crate) fn stub_response(status: &str, body: &str) -> String {
+    format!(
+        "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    )
+}
+
+#[test]
+fn test_fetch_input() {
+    let (base_url, server) = stub_server(vec![
+        stub_response("200 OK", "1\n2\n3\n"),
+        stub_response(
+            "404 Not Found",
+            "Please don't repeatedly request this endpoint.",
+        ),
+    ]);
+    let config = Config {
+        session: Some("abc123".to_owned()),
+        base_url,
+        path: None,
+    };
+    let dir = std::env::temp_dir().join(format!("aoc-fetch-{}", std::process::id()));
+    let path = dir.join("day1/input.txt");
+
+    assert_eq!(
+        fetch_input(&config, 2022, 1, &path).unwrap(),
+        Fetched::Downloaded
+    );
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "1\n2\n3\n");
+    assert_eq!(
+        fetch_input(&config, 2022, 1, &path).unwrap(),
+        Fetched::Cached
+    );
+    let err = fetch_input(&config, 2022, 25, &dir.join("day25/input.txt")).unwrap_err();
+    assert!(format!("{:#}", err).contains("not available yet"));
+    assert!(!dir.join("day25").exists());
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    let requests = server.join().unwrap();
+    assert_eq!(requests.len(), 2);
+    assert!(requests[0].starts_with("GET /2022/day/1/input HTTP/1.1\r\n"));
+    assert!(requests[0].contains("Cookie: session=abc123\r\n"));
+    assert!(requests[1].starts_with("GET /2022/day/25/input HTTP/1.1\r\n"));
+}
+
+#[test]
+fn test_submit_answer() {
+    let (base_url, server) = stub_server(vec![
+        stub_response(
+            "200 OK",
+            "<article><p>That's not the right answer; your answer is too low.</p></article>",
+        ),
+        stub_response(
+            "200 OK",
+            "<article><p>That's the right answer!</p></article>",
+        ),
+    ]);
+    let config = Config {
+        session: Some("abc123".to_owned()),
+        base_url,
+        path: None,
+    };
+
+    assert_eq!(
+        submit_answer(&config, 2022, 5, Part::Two, "CMZ 1").unwrap(),
+        Response::TooLow
+    );
+    assert_eq!(
+        submit_answer(&config, 2022, 5, Part::Two, "MCD").unwrap(),
+        Response::Correct
+    );
+
+    let requests = server.join().unwrap();
+    assert!(requests[0].starts_with("POST /2022/day/5/answer HTTP/1.1\r\n"));
+    assert!(requests[0].contains("Cookie: session=abc123\r\n"));
+    assert!(requests[0].ends_with("\r\n\r\nlevel=2&answer=CMZ+1"));
+    assert!(requests[1].ends_with("\r\n\r\nlevel=2&answer=MCD"));
+}
@@ -0,0 +1,14 @@
+//! Runs the solutions of all years, e.g. `aoc --year 2022 --day 16 --part 2` or `aoc --all`.
+
+use aoc_core::{aoc_main, memory::CountingAllocator};
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn main() -> anyhow::Result<()> {
+    aoc_main(&[
+        advent_of_code_2020::YEAR,
+        advent_of_code_2021::YEAR,
+        advent_of_code_2022::YEAR,
+    ])
+}